@@ -0,0 +1,23 @@
+// Operator alert types — lifecycle persisted in the orchestra event store
+
+export type AlertSeverity = "info" | "warning" | "critical";
+export type AlertState = "raised" | "acknowledged" | "resolved";
+
+export interface OperatorAlert {
+  alert_id: string;
+  entity_id?: string;
+  severity: AlertSeverity;
+  state: AlertState;
+  /** Node or subsystem that raised the alert */
+  source: string;
+  message: string;
+  raised_at: number;
+  acknowledged_by?: string;
+  acknowledged_at?: number;
+  resolved_at?: number;
+}
+
+export interface AlertAckCommand {
+  alert_id: string;
+  timestamp: number;
+}
@@ -0,0 +1,42 @@
+// Command arbitration types — per-tick trace and explicit control ownership
+
+export type CommandSource = "web" | "gamepad" | "voice" | "tracking" | "autonomy" | "safety";
+
+export interface ArbitrationCandidate {
+  source: CommandSource;
+  /** Command variant offered, e.g. "velocity", "stop", "joint_position" */
+  command_kind: string;
+  priority: number;
+  timestamp: number;
+}
+
+export interface ArbitrationDecision {
+  entity_id?: string;
+  winner: CommandSource | null;
+  /** Human-readable rule that decided, e.g. "safety override", "most recent manual input" */
+  reason: string;
+  candidates: ArbitrationCandidate[];
+  timestamp: number;
+}
+
+/** Single holder of motion control for a rover */
+export interface ControlOwner {
+  source: CommandSource;
+  /** Socket ID when source is "web" */
+  client_id?: string;
+  username?: string;
+  priority: number;
+  since: number;
+}
+
+export interface ControlOwnership {
+  entity_id: string;
+  owner: ControlOwner | null;
+  timestamp: number;
+}
+
+/** "steal" succeeds only if the requester's priority is at least the owner's */
+export interface ControlRequest {
+  action: "take" | "release" | "steal";
+  entity_id?: string;
+}
@@ -0,0 +1,93 @@
+// Audio playback control types — mirrors AudioControl in robo_rover_lib
+
+export interface AudioOutputDevice {
+  /** Backend device identifier (ALSA/PulseAudio name) */
+  id: string;
+  name: string;
+  is_default: boolean;
+  channels: number;
+  sample_rates: number[];
+}
+
+export type AudioControlCommand =
+  | { command: "start" }
+  | { command: "stop" }
+  | { command: "set_output_device"; device_id: string }
+  | { command: "set_volume"; volume: number } // 0.0 – 1.0
+  | { command: "mute"; muted: boolean };
+
+export interface AudioOutputStatus {
+  entity_id?: string;
+  device_id: string;
+  volume: number;
+  muted: boolean;
+  available_devices: AudioOutputDevice[];
+  timestamp: number;
+}
+
+/** Direction-of-arrival estimate from a multi-mic array, rover body frame */
+export interface SoundDirection {
+  entity_id?: string;
+  /** Bearing in radians, 0 = straight ahead, positive = counter-clockwise */
+  bearing: number;
+  /** 0.0 – 1.0, how dominant the source is over the noise floor */
+  confidence: number;
+  is_speech: boolean;
+  timestamp: number;
+}
+
+/** Urgent preempts any queued Normal/Low speech on the rover */
+export type TtsPriority = "low" | "normal" | "urgent";
+
+export interface TtsCommand {
+  text: string;
+  /** Backend-specific voice name; omitted uses the node's default voice */
+  voice?: string;
+  /** Speaking rate multiplier, 1.0 = default */
+  rate?: number;
+  /** Pitch multiplier, 1.0 = default */
+  pitch?: number;
+  priority?: TtsPriority;
+}
+
+// Intercom — full-duplex operator ↔ rover voice sessions
+
+export interface IntercomStartRequest {
+  entity_id?: string;
+  /** Operator mic capture format for the uplink */
+  sample_rate: number;
+  channels: number;
+  format: "s16le";
+}
+
+export interface IntercomStopRequest {
+  session_id: string;
+}
+
+export type IntercomSessionState = "active" | "closed" | "rejected";
+
+export interface IntercomSession {
+  session_id: string;
+  entity_id: string;
+  state: IntercomSessionState;
+  /** Why the session was rejected or closed (busy, timeout, operator_stop) */
+  reason?: string;
+  timestamp: number;
+}
+
+export interface IntercomStats {
+  session_id: string;
+  /** Round-trip latency estimate in ms */
+  latency_ms: number;
+  /** Current rover-side jitter buffer depth in ms */
+  jitter_buffer_ms: number;
+  packets_received: number;
+  packets_dropped: number;
+  timestamp: number;
+}
+
+export interface AudioStreamChunk {
+  audio_data: number[]; // s16le PCM bytes
+  /** Set while an intercom session is active */
+  session_id?: string;
+}
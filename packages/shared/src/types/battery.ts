@@ -0,0 +1,15 @@
+// Battery telemetry types — mirrors BatteryTelemetry in robo_rover_lib
+
+export type BatteryState = "ok" | "low" | "critical" | "charging";
+
+export interface BatteryTelemetry {
+  entity_id?: string;
+  voltage: number;
+  current: number; // amps, negative when discharging
+  percentage: number; // 0 – 100
+  state: BatteryState;
+  /** Speed cap applied by the low-battery policy, null when unrestricted */
+  max_speed_limit: number | null;
+  estimated_minutes_remaining?: number;
+  timestamp: number;
+}
@@ -0,0 +1,22 @@
+// Behavior tree telemetry types — published by the BT executor on every tick
+
+export type BehaviorNodeKind = "sequence" | "fallback" | "parallel" | "decorator" | "condition" | "action";
+
+export type BehaviorNodeStatus = "idle" | "running" | "success" | "failure";
+
+export interface BehaviorTreeNode {
+  id: string;
+  name: string;
+  kind: BehaviorNodeKind;
+  status: BehaviorNodeStatus;
+  children: BehaviorTreeNode[];
+}
+
+export interface BehaviorTreeStatus {
+  entity_id?: string;
+  /** Tree name from the config file */
+  tree: string;
+  root: BehaviorTreeNode;
+  tick: number;
+  timestamp: number;
+}
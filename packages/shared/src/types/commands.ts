@@ -1,5 +1,11 @@
 // Command types for controlling the rover and arm
 
+/** Routing for a command — omitted means the currently selected rover */
+export type CommandTarget =
+  | { mode: "selected" }
+  | { mode: "all_active" }
+  | { mode: "explicit"; entity_ids: string[] };
+
 export interface JointPositions {
   shoulder_pan: number;
   shoulder_lift: number;
@@ -12,10 +18,43 @@ export interface JointPositions {
   wheel3?: number;
 }
 
+/** Per-joint limits — mirrors JointLimits in robo_rover_lib (radians, rad/s, rad/s²) */
+export interface JointLimit {
+  min: number;
+  max: number;
+  max_velocity: number;
+  max_acceleration: number;
+}
+
+export type ArmJointName = Exclude<keyof JointPositions, "wheel1" | "wheel2" | "wheel3">;
+
+export type JointLimitsConfig = Record<ArmJointName, JointLimit>;
+
+/** End-effector pose in the arm base frame (meters, radians) */
+export interface CartesianPose {
+  x: number;
+  y: number;
+  z: number;
+  roll: number;
+  pitch: number;
+  yaw: number;
+}
+
+/** Parallel-jaw gripper target — width in meters, force in newtons */
+export interface GripperCommand {
+  width: number;
+  max_force: number;
+}
+
 export interface WebArmCommand {
-  command_type: "joint_position" | "cartesian" | "home" | "stop";
+  command_type: "joint_position" | "cartesian" | "gripper" | "home" | "stop";
   joint_positions?: JointPositions;
+  /** Required when command_type is "cartesian" */
+  pose?: CartesianPose;
+  /** Required when command_type is "gripper" */
+  gripper?: GripperCommand;
   max_velocity?: number;
+  target?: CommandTarget;
 }
 
 export interface WebRoverCommand {
@@ -26,10 +65,41 @@ export interface WebRoverCommand {
   wheel1?: number;
   wheel2?: number;
   wheel3?: number;
+  target?: CommandTarget;
+}
+
+/** Per-class tracker behavior — mirrors TrackingCommand::SetClassPolicy */
+export interface ClassTrackingPolicy {
+  class_name: string;
+  /** Detections of this class are never tracked */
+  ignore: boolean;
+  /** Start tracking automatically when this class appears and no target is selected */
+  auto_track: boolean;
+  min_confidence: number;
 }
 
 export interface WebTrackingCommand {
-  command_type: "enable" | "disable" | "enable_detection" | "disable_detection" | "select_target" | "clear_target";
+  command_type:
+    | "enable"
+    | "disable"
+    | "enable_detection"
+    | "disable_detection"
+    | "select_target"
+    | "clear_target"
+    | "set_class_policy"
+    | "select_target_at_point";
   tracking_id?: number;
   detection_index?: number;
+  /** Normalized image coordinates (0.0 – 1.0), required for "select_target_at_point" */
+  point?: { x: number; y: number };
+  /** Required when command_type is "set_class_policy" */
+  class_policy?: ClassTrackingPolicy;
+}
+
+/** Dead-man switch keepalive sent while rover motion is commanded — web_bridge stops the rover after timeout_ms without one */
+export interface TeleopHeartbeat {
+  seq: number;
+  timestamp: number;
+  /** Trip threshold; kept well above the ~1s timer clamp of background browser tabs */
+  timeout_ms: number;
 }
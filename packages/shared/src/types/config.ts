@@ -0,0 +1,38 @@
+// Fleet configuration bundle types — admin export/import between orchestra instances
+
+export interface ConfigBundleManifest {
+  format_version: number;
+  source_instance: string;
+  created_at: number;
+  created_by: string;
+  /** Sections present in the bundle, e.g. "fleet_roster", "params", "poses", "missions" */
+  sections: string[];
+}
+
+export interface ConfigBundle {
+  manifest: ConfigBundleManifest;
+  /** Section name → opaque section payload */
+  sections: Record<string, unknown>;
+  /** Base64 HMAC-SHA256 over manifest + sections, verified on import */
+  signature: string;
+}
+
+export interface ConfigExportRequest {
+  /** Omit to export every section */
+  sections?: string[];
+}
+
+export interface ConfigImportRequest {
+  bundle: ConfigBundle;
+  /** Validate and report without applying */
+  dry_run?: boolean;
+}
+
+export interface ConfigImportResult {
+  success: boolean;
+  dry_run: boolean;
+  applied_sections: string[];
+  skipped_sections: string[];
+  errors: string[];
+  timestamp: number;
+}
@@ -0,0 +1,35 @@
+// Pipeline diagnostics types — recoverable node errors aggregated by web_bridge
+
+export type NodeErrorKind = "deserialize" | "device" | "transport" | "config" | "other";
+
+export interface NodeError {
+  /** Dora node ID, e.g. "object_detector" */
+  node: string;
+  /** Absent for orchestra-side nodes */
+  entity_id?: string;
+  kind: NodeErrorKind;
+  message: string;
+  /** Occurrences since the first report; repeats are coalesced by the bridge */
+  count: number;
+  first_seen: number;
+  timestamp: number;
+}
+
+export type NodeHealthState = "alive" | "stalled" | "erroring";
+
+export interface NodeHealth {
+  node: string;
+  entity_id?: string;
+  state: NodeHealthState;
+  /** Time since the node last received any input */
+  last_input_age_ms: number;
+  /** Pending messages per input ID */
+  queue_depths: Record<string, number>;
+  timestamp: number;
+}
+
+/** Dataflow-wide view aggregated by performance_monitor */
+export interface DataflowHealth {
+  nodes: NodeHealth[];
+  timestamp: number;
+}
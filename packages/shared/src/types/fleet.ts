@@ -29,3 +29,28 @@ export interface ActiveRoversStatus {
   active_rovers: string[];
   timestamp: number;
 }
+
+export type RoverPresence = "online" | "offline";
+
+/** Emitted when a rover's Zenoh liveliness token appears or disappears */
+export interface FleetDiscoveryEvent {
+  entity_id: string;
+  presence: RoverPresence;
+  timestamp: number;
+}
+
+export interface TopicHealth {
+  topic: string;
+  last_seen: number;
+  message_rate_hz: number;
+}
+
+/** Per-rover link health computed by orchestra/zenoh_bridge — mirrors RoverHealth in robo_rover_lib */
+export interface RoverHealth {
+  entity_id: string;
+  is_alive: boolean;
+  last_seen: number;
+  rtt_ms?: number;
+  topics: TopicHealth[];
+  timestamp: number;
+}
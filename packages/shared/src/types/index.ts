@@ -2,7 +2,15 @@
 
 // Commands
 export type {
+  ArmJointName,
+  CartesianPose,
+  ClassTrackingPolicy,
+  CommandTarget,
+  GripperCommand,
+  JointLimit,
+  JointLimitsConfig,
   JointPositions,
+  TeleopHeartbeat,
   WebArmCommand,
   WebRoverCommand,
   WebTrackingCommand,
@@ -29,6 +37,14 @@ export type {
   TrackingTarget,
   TrackingTelemetry,
   ControlOutput,
+  DetectionPolicy,
+  ApproachState,
+  SearchMode,
+  SearchState,
+  TargetSearchStatus,
+  PidGains,
+  ServoConfig,
+  ServoConfigUpdate,
 } from "./tracking";
 
 // Voice
@@ -46,6 +62,12 @@ export type {
   ClientToServerEvents,
   AuthErrorReason,
   AuthErrorEvent,
+  CommandAck,
+  RateLimitNotice,
+  ServerEventName,
+  ClientEventName,
+  ServerEventPayload,
+  ClientEventPayload,
 } from "./socket";
 
 // Fleet
@@ -55,4 +77,151 @@ export type {
   RoverStatus,
   FleetRosterUpdate,
   ActiveRoversStatus,
+  RoverPresence,
+  FleetDiscoveryEvent,
+  TopicHealth,
+  RoverHealth,
 } from "./fleet";
+
+// Audio
+export type {
+  AudioOutputDevice,
+  AudioControlCommand,
+  AudioOutputStatus,
+  SoundDirection,
+  TtsPriority,
+  TtsCommand,
+  IntercomStartRequest,
+  IntercomStopRequest,
+  IntercomSessionState,
+  IntercomSession,
+  IntercomStats,
+  AudioStreamChunk,
+} from "./audio";
+
+// Stream
+export type {
+  ResolutionTier,
+  CameraCalibration,
+  StreamSettings,
+  StreamSettingsAck,
+  CameraInfo,
+  CameraList,
+  CameraSelectCommand,
+  CapturePhotoRequest,
+  PhotoMetadata,
+  PhotoCaptured,
+  RecordingCommand,
+  RecordingStatus,
+  TelemetryTopic,
+  SubscriptionUpdate,
+} from "./stream";
+
+// Preferences
+export type {
+  JoystickCurve,
+  LayoutHints,
+  UserPreferences,
+  PreferencesUpdate,
+} from "./preferences";
+
+// Config bundles
+export type {
+  ConfigBundleManifest,
+  ConfigBundle,
+  ConfigExportRequest,
+  ConfigImportRequest,
+  ConfigImportResult,
+} from "./config";
+
+// Arbitration
+export type {
+  CommandSource,
+  ArbitrationCandidate,
+  ArbitrationDecision,
+  ControlOwner,
+  ControlOwnership,
+  ControlRequest,
+} from "./arbitration";
+
+// Alerts
+export type {
+  AlertSeverity,
+  AlertState,
+  OperatorAlert,
+  AlertAckCommand,
+} from "./alerts";
+
+// Inventory
+export type {
+  BuildProfile,
+  VersionInfo,
+  RoverInventory,
+  FleetInventory,
+} from "./inventory";
+
+// Macros
+export type {
+  MacroSummary,
+  MacroRecorderState,
+  MacroStatus,
+  MacroCommand,
+} from "./macros";
+
+// Safety
+export type {
+  SafetyViolationKind,
+  SafetyAction,
+  SafetyViolation,
+  RangeReading,
+  ObstacleGuardConfig,
+  ObstacleGuardStatus,
+} from "./safety";
+
+// Battery
+export type { BatteryState, BatteryTelemetry } from "./battery";
+
+// Navigation
+export type {
+  Pose2D,
+  HomingCommand,
+  HomingState,
+  HomingStatus,
+  ExploreCommand,
+  ExploreState,
+  ExploreStatus,
+  DockingCommand,
+  DockingState,
+  DockingStatus,
+} from "./navigation";
+
+// Diagnostics
+export type {
+  NodeErrorKind,
+  NodeError,
+  NodeHealthState,
+  NodeHealth,
+  DataflowHealth,
+} from "./diagnostics";
+
+// Params
+export type {
+  ParamValue,
+  ParamKind,
+  ParamDescriptor,
+  ParamList,
+  ParamGetRequest,
+  ParamSetRequest,
+  ParamUpdate,
+} from "./params";
+
+// Presence
+export type { OperatorRole, OperatorPresence, PresenceUpdate } from "./presence";
+
+// Behavior
+export type {
+  BehaviorNodeKind,
+  BehaviorNodeStatus,
+  BehaviorTreeNode,
+  BehaviorTreeStatus,
+} from "./behavior";
@@ -0,0 +1,25 @@
+// Software inventory types — each node reports VersionInfo at startup
+
+export type BuildProfile = "debug" | "release";
+
+export interface VersionInfo {
+  node_id: string;
+  crate_name: string;
+  crate_version: string;
+  git_hash: string;
+  build_profile: BuildProfile;
+  started_at: number;
+}
+
+export interface RoverInventory {
+  entity_id: string;
+  nodes: VersionInfo[];
+}
+
+export interface FleetInventory {
+  orchestra: VersionInfo[];
+  rovers: RoverInventory[];
+  /** True when any two nodes of the same crate report different git hashes */
+  mixed_versions: boolean;
+  timestamp: number;
+}
@@ -0,0 +1,28 @@
+// Arm motion macro types — recorded joint waypoints stored under macros/ on the orchestra host
+
+export interface MacroSummary {
+  name: string;
+  waypoint_count: number;
+  duration_ms: number;
+  created_at: number;
+}
+
+export type MacroRecorderState = "idle" | "recording" | "replaying";
+
+export interface MacroStatus {
+  state: MacroRecorderState;
+  /** Macro being recorded or replayed */
+  name?: string;
+  /** Replay progress 0.0 – 1.0 */
+  progress?: number;
+  macros: MacroSummary[];
+  timestamp: number;
+}
+
+export type MacroCommand =
+  | { command: "record_start"; name: string }
+  | { command: "record_stop" }
+  | { command: "replay"; name: string; speed_scale: number }
+  | { command: "replay_stop" }
+  | { command: "delete"; name: string }
+  | { command: "list" };
@@ -0,0 +1,69 @@
+// Navigation behavior types — return-to-home, docking and exploration
+
+/** Planar pose in the odometry frame: meters, radians */
+export interface Pose2D {
+  x: number;
+  y: number;
+  yaw: number;
+}
+
+export type HomingCommand =
+  | { command: "return_home" }
+  | { command: "set_home"; pose?: Pose2D } // omit pose to use the current pose
+  | { command: "cancel" };
+
+export type HomingState = "idle" | "navigating" | "blocked" | "arrived" | "cancelled";
+
+export interface HomingStatus {
+  entity_id?: string;
+  state: HomingState;
+  home_pose: Pose2D;
+  distance_remaining_m: number;
+  /** 0.0 – 1.0 of the straight-line distance at start */
+  progress: number;
+  timestamp: number;
+}
+
+export type ExploreCommand =
+  | { command: "start"; max_duration_s: number; max_area_m2?: number }
+  | { command: "stop" };
+
+export type ExploreState = "idle" | "exploring" | "complete" | "timeout" | "stopped";
+
+export interface ExploreStatus {
+  entity_id?: string;
+  state: ExploreState;
+  /** Frontiers still reachable inside the geofence */
+  frontiers_remaining: number;
+  explored_area_m2: number;
+  elapsed_s: number;
+  timestamp: number;
+}
+
+export type DockingCommand =
+  | { command: "dock" }
+  | { command: "undock" }
+  | { command: "cancel" };
+
+export type DockingState =
+  | "idle"
+  | "searching_marker"
+  | "aligning"
+  | "final_approach"
+  | "docked"
+  | "failed";
+
+export interface DockingStatus {
+  entity_id?: string;
+  state: DockingState;
+  /** Dock fiducial marker ID being approached */
+  marker_id?: number;
+  /** Remaining errors relative to the dock marker, present once it is in view */
+  distance_m?: number;
+  lateral_error_m?: number;
+  heading_error_rad?: number;
+  /** Started by the low-battery policy rather than an operator command */
+  auto_triggered: boolean;
+  reason?: string;
+  timestamp: number;
+}
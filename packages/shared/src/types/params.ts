@@ -0,0 +1,47 @@
+// Runtime parameter types — tunables registered by nodes with the parameter node
+
+export type ParamValue = number | boolean | string;
+export type ParamKind = "float" | "int" | "bool" | "string";
+
+export interface ParamDescriptor {
+  /** Owning Dora node, e.g. "object_tracker" */
+  node: string;
+  /** Parameter name within the node, e.g. "iou_threshold" */
+  name: string;
+  kind: ParamKind;
+  value: ParamValue;
+  default: ParamValue;
+  min?: number;
+  max?: number;
+  description?: string;
+}
+
+export interface ParamList {
+  entity_id?: string;
+  params: ParamDescriptor[];
+  timestamp: number;
+}
+
+/** Omit node to list every registered parameter */
+export interface ParamGetRequest {
+  entity_id?: string;
+  node?: string;
+}
+
+export interface ParamSetRequest {
+  entity_id?: string;
+  node: string;
+  name: string;
+  value: ParamValue;
+}
+
+/** Result of a set, also broadcast to other clients when accepted */
+export interface ParamUpdate {
+  entity_id?: string;
+  node: string;
+  name: string;
+  value: ParamValue;
+  accepted: boolean;
+  error?: string;
+  timestamp: number;
+}
@@ -0,0 +1,29 @@
+// Per-user preferences — stored server-side by web_bridge, keyed by username
+
+import type { StreamSettings } from "./stream";
+
+export interface JoystickCurve {
+  deadzone: number; // 0.0 – 1.0 of stick travel
+  exponent: number; // 1.0 = linear
+  max_linear: number; // m/s
+  max_angular: number; // rad/s
+}
+
+export interface LayoutHints {
+  show_camera?: boolean;
+  show_location_map?: boolean;
+  expanded_sections?: Record<string, boolean>;
+}
+
+export interface UserPreferences {
+  default_rover?: string;
+  stream?: StreamSettings;
+  /** Event name → max emit rate in Hz */
+  telemetry_rates?: Record<string, number>;
+  joystick?: JoystickCurve;
+  layout?: LayoutHints;
+  updated_at: number;
+}
+
+/** Partial update — top-level fields present replace the stored value */
+export type PreferencesUpdate = Partial<Omit<UserPreferences, "updated_at">>;
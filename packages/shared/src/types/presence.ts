@@ -0,0 +1,22 @@
+// Operator presence types — connected web clients as tracked by web_bridge
+
+export type OperatorRole = "admin" | "operator" | "viewer";
+
+export interface OperatorPresence {
+  /** Socket ID, matches ControlOwner.client_id */
+  client_id: string;
+  username: string;
+  role: OperatorRole;
+  /** Rover this operator currently has selected */
+  selected_rover: string | null;
+  /** Rovers this operator holds motion control of */
+  controlled_rovers: string[];
+  /** Seconds since this client last sent a command */
+  idle_s: number;
+  connected_at: number;
+}
+
+export interface PresenceUpdate {
+  operators: OperatorPresence[];
+  timestamp: number;
+}
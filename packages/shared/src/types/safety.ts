@@ -0,0 +1,45 @@
+// Safety layer types — emitted when a safety stage overrides operator commands
+
+export type SafetyViolationKind = "geofence" | "obstacle";
+
+export type SafetyAction = "suppressed" | "scaled" | "stopped";
+
+export interface SafetyViolation {
+  entity_id?: string;
+  kind: SafetyViolationKind;
+  action: SafetyAction;
+  /** Zone or guard that intervened, e.g. "keep_in:yard" */
+  source: string;
+  message: string;
+  /** Odometry position [x, y] in meters when the violation occurred */
+  position?: [number, number];
+  timestamp: number;
+}
+
+/** Single ultrasonic/ToF measurement — mirrors RangeReading in robo_rover_lib */
+export interface RangeReading {
+  entity_id?: string;
+  sensor_id: string;
+  /** Mounting bearing in the body frame, radians (0 = forward) */
+  bearing: number;
+  distance_m: number;
+  min_range_m: number;
+  max_range_m: number;
+  timestamp: number;
+}
+
+export interface ObstacleGuardConfig {
+  enabled: boolean;
+  /** Forward motion is blocked inside this distance */
+  stop_distance_m: number;
+  /** Forward velocity is scaled down linearly inside this distance */
+  slow_distance_m: number;
+}
+
+export interface ObstacleGuardStatus extends ObstacleGuardConfig {
+  entity_id?: string;
+  /** Closest reading in the direction of travel, null when nothing in range */
+  nearest_m: number | null;
+  intervening: boolean;
+  timestamp: number;
+}
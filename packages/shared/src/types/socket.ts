@@ -11,33 +11,173 @@ export interface AuthErrorEvent {
 }
 
 import type { VideoFrame } from "./telemetry";
-import type { DetectionFrame, TrackingTelemetry } from "./tracking";
-import type { WebArmCommand, WebRoverCommand, WebTrackingCommand } from "./commands";
+import type { DetectionFrame, DetectionPolicy, ServoConfigUpdate, TrackingTelemetry } from "./tracking";
+import type {
+  JointLimitsConfig,
+  TeleopHeartbeat,
+  WebArmCommand,
+  WebRoverCommand,
+  WebTrackingCommand,
+} from "./commands";
 import type { SpeechTranscription } from "./voice";
 import type { SystemMetrics } from "./performance";
-import type { FleetStatus, FleetSelectCommand, ActiveRoversStatus } from "./fleet";
+import type {
+  FleetStatus,
+  FleetSelectCommand,
+  ActiveRoversStatus,
+  FleetDiscoveryEvent,
+  RoverHealth,
+} from "./fleet";
+import type {
+  AudioControlCommand,
+  AudioOutputStatus,
+  AudioStreamChunk,
+  IntercomSession,
+  IntercomStartRequest,
+  IntercomStats,
+  IntercomStopRequest,
+  SoundDirection,
+  TtsCommand,
+} from "./audio";
+import type {
+  CameraList,
+  CameraSelectCommand,
+  CapturePhotoRequest,
+  PhotoCaptured,
+  RecordingCommand,
+  RecordingStatus,
+  StreamSettings,
+  StreamSettingsAck,
+  SubscriptionUpdate,
+} from "./stream";
+import type { PreferencesUpdate, UserPreferences } from "./preferences";
+import type { ArbitrationDecision, ControlOwnership, ControlRequest } from "./arbitration";
+import type { AlertAckCommand, OperatorAlert } from "./alerts";
+import type { FleetInventory } from "./inventory";
+import type { MacroCommand, MacroStatus } from "./macros";
+import type { ObstacleGuardConfig, ObstacleGuardStatus, RangeReading, SafetyViolation } from "./safety";
+import type { ConfigBundle, ConfigExportRequest, ConfigImportRequest, ConfigImportResult } from "./config";
+import type { BatteryTelemetry } from "./battery";
+import type {
+  DockingCommand,
+  DockingStatus,
+  ExploreCommand,
+  ExploreStatus,
+  HomingCommand,
+  HomingStatus,
+} from "./navigation";
+import type { BehaviorTreeStatus } from "./behavior";
+import type { PresenceUpdate } from "./presence";
+import type { DataflowHealth, NodeError } from "./diagnostics";
+import type { ParamGetRequest, ParamList, ParamSetRequest, ParamUpdate } from "./params";
+
+export interface CommandAck {
+  command_type: string;
+  success: boolean;
+  error?: string;
+  timestamp: number;
+}
+
+/** Sent to the offending client when one of its events exceeds its per-type limit */
+export interface RateLimitNotice {
+  /** Client event name that was dropped, e.g. "rover_command" */
+  event: string;
+  limit_per_sec: number;
+  retry_after_ms: number;
+  timestamp: number;
+}
 
 export interface ServerToClientEvents {
+  auth_token: (token: string) => void;
+  auth_error: (event: AuthErrorEvent) => void;
+  command_ack: (ack: CommandAck) => void;
+  rate_limited: (notice: RateLimitNotice) => void;
   video_frame: (frame: VideoFrame) => void;
   audio_frame: (frame: { timestamp: number; frame_id: number; sample_rate: number; channels: number; format: string; data: number[] }) => void;
   detections: (frame: DetectionFrame) => void;
   tracked_detections: (frame: DetectionFrame) => void;
   tracking_telemetry: (telemetry: TrackingTelemetry) => void;
+  detection_policy: (policy: DetectionPolicy) => void;
   servo_telemetry: (telemetry: TrackingTelemetry) => void;
   transcription: (data: SpeechTranscription) => void;
   performance_metrics: (metrics: SystemMetrics) => void;
   fleet_status: (status: FleetStatus) => void;
   active_rovers_status: (status: ActiveRoversStatus) => void;
+  fleet_discovery: (event: FleetDiscoveryEvent) => void;
+  rover_health: (health: RoverHealth) => void;
+  audio_output_status: (status: AudioOutputStatus) => void;
+  intercom_session: (session: IntercomSession) => void;
+  intercom_stats: (stats: IntercomStats) => void;
+  stream_settings_ack: (settings: StreamSettingsAck) => void;
+  camera_list: (list: CameraList) => void;
+  photo_captured: (photo: PhotoCaptured) => void;
+  recording_status: (status: RecordingStatus) => void;
+  sound_direction: (direction: SoundDirection) => void;
+  preferences: (preferences: UserPreferences) => void;
+  config_bundle: (bundle: ConfigBundle) => void;
+  config_import_result: (result: ConfigImportResult) => void;
+  arbitration_trace: (decision: ArbitrationDecision) => void;
+  control_ownership: (ownership: ControlOwnership) => void;
+  presence: (update: PresenceUpdate) => void;
+  node_error: (error: NodeError) => void;
+  node_health: (health: DataflowHealth) => void;
+  param_list: (list: ParamList) => void;
+  param_update: (update: ParamUpdate) => void;
+  operator_alert: (alert: OperatorAlert) => void;
+  fleet_inventory: (inventory: FleetInventory) => void;
+  deadman_triggered: (event: { entity_id?: string; timestamp: number }) => void;
+  joint_limits: (limits: JointLimitsConfig) => void;
+  macro_status: (status: MacroStatus) => void;
+  safety_violation: (violation: SafetyViolation) => void;
+  range_reading: (reading: RangeReading) => void;
+  obstacle_guard_status: (status: ObstacleGuardStatus) => void;
+  battery_telemetry: (battery: BatteryTelemetry) => void;
+  homing_status: (status: HomingStatus) => void;
+  docking_status: (status: DockingStatus) => void;
+  explore_status: (status: ExploreStatus) => void;
+  behavior_tree_status: (status: BehaviorTreeStatus) => void;
 }
 
 export interface ClientToServerEvents {
+  auth_refresh: (request: { token: string }) => void;
   arm_command: (command: WebArmCommand) => void;
   rover_command: (command: WebRoverCommand) => void;
   tracking_command: (command: WebTrackingCommand) => void;
+  servo_config: (update: ServoConfigUpdate) => void;
+  param_get: (request: ParamGetRequest) => void;
+  param_set: (request: ParamSetRequest) => void;
   camera_control: (control: { command: string }) => void;
-  audio_control: (control: { command: string }) => void;
-  tts_command: (command: { text: string }) => void;
-  audio_stream: (data: { audio_data: number[] }) => void;
+  audio_control: (control: AudioControlCommand) => void;
+  tts_command: (command: TtsCommand) => void;
+  audio_stream: (data: AudioStreamChunk) => void;
+  intercom_start: (request: IntercomStartRequest) => void;
+  intercom_stop: (request: IntercomStopRequest) => void;
   performance_control: (control: { enabled: boolean }) => void;
   fleet_select: (command: FleetSelectCommand) => void;
+  control_request: (request: ControlRequest) => void;
+  stream_settings: (settings: StreamSettings) => void;
+  subscribe: (update: SubscriptionUpdate) => void;
+  camera_select: (command: CameraSelectCommand) => void;
+  capture_photo: (request: CapturePhotoRequest) => void;
+  recording_command: (command: RecordingCommand) => void;
+  preferences_update: (update: PreferencesUpdate) => void;
+  config_export: (request: ConfigExportRequest) => void;
+  config_import: (request: ConfigImportRequest) => void;
+  alert_ack: (command: AlertAckCommand) => void;
+  fleet_inventory_request: () => void;
+  heartbeat: (beat: TeleopHeartbeat) => void;
+  macro_command: (command: MacroCommand) => void;
+  obstacle_guard: (config: Partial<ObstacleGuardConfig>) => void;
+  homing_command: (command: HomingCommand) => void;
+  docking_command: (command: DockingCommand) => void;
+  explore_command: (command: ExploreCommand) => void;
 }
+
+export type ServerEventName = keyof ServerToClientEvents;
+export type ClientEventName = keyof ClientToServerEvents;
+
+/** Payload of a server → client event, e.g. `ServerEventPayload<"fleet_status">` */
+export type ServerEventPayload<E extends ServerEventName> = Parameters<ServerToClientEvents[E]>[0];
+
+/** Payload of a client → server event, e.g. `ClientEventPayload<"rover_command">` */
+export type ClientEventPayload<E extends ClientEventName> = Parameters<ClientToServerEvents[E]>[0];
@@ -0,0 +1,121 @@
+// Per-client stream preference types — applied to the client's ClientState in web_bridge
+
+export type ResolutionTier = "low" | "medium" | "high" | "native";
+
+/** All fields optional — the server only updates the fields that are present */
+export interface StreamSettings {
+  target_fps?: number;
+  jpeg_quality?: number; // 1 – 100
+  resolution_tier?: ResolutionTier;
+  video_enabled?: boolean;
+  audio_enabled?: boolean;
+  detections_enabled?: boolean;
+}
+
+/** Echo of the effective settings after the server clamps/validates them */
+export interface StreamSettingsAck {
+  target_fps: number;
+  jpeg_quality: number;
+  resolution_tier: ResolutionTier;
+  video_enabled: boolean;
+  audio_enabled: boolean;
+  detections_enabled: boolean;
+}
+
+/** Pinhole calibration, shared with the detector/tracker/servo nodes via camera_info */
+export interface CameraCalibration {
+  /** Row-major 3x3 intrinsic matrix [fx, 0, cx, 0, fy, cy, 0, 0, 1] */
+  intrinsics: number[];
+  /** Plumb-bob distortion coefficients [k1, k2, p1, p2, k3] */
+  distortion: number[];
+  /** Camera pose in base_link: translation in meters, rotation as quaternion [x, y, z, w] */
+  mount_transform: { translation: [number, number, number]; rotation: [number, number, number, number] };
+  /** Resolution the calibration was taken at */
+  width: number;
+  height: number;
+}
+
+export interface CameraInfo {
+  /** Matches the `camera_id` tag in video_frame metadata */
+  camera_id: string;
+  name: string;
+  mount: "front" | "rear" | "arm" | "other";
+  width: number;
+  height: number;
+  detection_enabled: boolean;
+  /** Absent for uncalibrated cameras */
+  calibration?: CameraCalibration;
+}
+
+export interface CameraList {
+  entity_id?: string;
+  cameras: CameraInfo[];
+  timestamp: number;
+}
+
+/** Which cameras this client wants to receive frames from */
+export interface CameraSelectCommand {
+  camera_ids: string[];
+}
+
+export interface CapturePhotoRequest {
+  /** Defaults to the client's active camera */
+  camera_id?: string;
+  request_id: string;
+}
+
+export interface PhotoMetadata {
+  entity_id: string;
+  camera_id?: string;
+  width: number;
+  height: number;
+  timestamp: number;
+  /** Rover pose at capture time: [x, y, yaw] */
+  pose?: [number, number, number];
+}
+
+export interface PhotoCaptured {
+  request_id: string;
+  success: boolean;
+  error?: string;
+  /** Path on the rover where the full-resolution still was saved */
+  stored_path?: string;
+  /** Download URL served by web_bridge, if available */
+  url?: string;
+  /** Inline JPEG bytes when no URL is served */
+  data?: number[];
+  metadata?: PhotoMetadata;
+}
+
+export type RecordingCommand =
+  | { command: "start_recording"; include_audio?: boolean }
+  | { command: "stop_recording" }
+  | { command: "mark_event"; label: string };
+
+/** On-rover segmented recorder state — oldest segments are evicted past retention */
+export interface RecordingStatus {
+  entity_id?: string;
+  is_recording: boolean;
+  include_audio: boolean;
+  current_segment?: string;
+  segment_count: number;
+  retained_bytes: number;
+  retention_bytes: number;
+  started_at?: number;
+  timestamp: number;
+}
+
+// Per-client telemetry subscriptions — every topic starts subscribed on connect
+
+export type TelemetryTopic =
+  | "detections"
+  | "tracked_detections"
+  | "tracking_telemetry"
+  | "servo_telemetry"
+  | "performance_metrics"
+  | "transcription";
+
+export interface SubscriptionUpdate {
+  subscribe: TelemetryTopic[];
+  unsubscribe: TelemetryTopic[];
+}
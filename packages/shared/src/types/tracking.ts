@@ -13,6 +13,8 @@ export interface DetectionResult {
   class_name: string;
   confidence: number;
   tracking_id?: number;
+  /** Median depth inside the bbox from the depth camera, meters */
+  depth_m?: number;
 }
 
 export interface DetectionFrame {
@@ -34,6 +36,11 @@ export interface DetectionDisplaySettings {
 
 export type TrackingState = "Disabled" | "DetectionOnly" | "Enabled" | "Tracking" | "TargetLost";
 export type ControlMode = "Manual" | "Autonomous";
+/** Follow-mode standoff behavior relative to target_distance_m */
+export type ApproachState = "Approaching" | "Holding" | "Backing";
+/** Lost-target search behavior run by the servo controller */
+export type SearchMode = "off" | "rotate" | "gimbal_sweep";
+export type SearchState = "Scanning" | "GaveUp";
 
 export interface TrackingTarget {
   tracking_id: number;
@@ -50,6 +57,12 @@ export interface TrackingTelemetry {
   distance_estimate: number | null;
   control_output: ControlOutput | null;
   control_mode: ControlMode;
+  /** Live visual-servo parameters, echoed so the UI reflects what is applied */
+  config?: ServoConfig;
+  /** Present while following a target */
+  approach_state?: ApproachState;
+  /** Present while the target is lost and a search is running or has given up */
+  search?: TargetSearchStatus;
   timestamp: number;
 }
 
@@ -59,3 +72,53 @@ export interface ControlOutput {
   error_x: number;
   error_size: number;
 }
+
+export interface TargetSearchStatus {
+  state: SearchState;
+  mode: SearchMode;
+  /** Rotation direction, taken from the target's last-known side */
+  direction: "left" | "right";
+  elapsed_s: number;
+}
+
+export interface PidGains {
+  kp: number;
+  ki: number;
+  kd: number;
+}
+
+export interface ServoConfig {
+  /** Steering loop on horizontal bbox error */
+  yaw_pid: PidGains;
+  /** Approach loop on bbox-size / distance error */
+  distance_pid: PidGains;
+  /** Standoff distance follow mode approaches to and station-keeps at */
+  target_distance_m: number;
+  /** Band around the standoff distance where the rover holds position */
+  standoff_hysteresis_m: number;
+  /** Normalized horizontal error below which no turn is commanded */
+  deadband_x: number;
+  /** Normalized size error below which no approach is commanded */
+  deadband_size: number;
+  max_linear_velocity: number;
+  max_angular_velocity: number;
+  search_mode: SearchMode;
+  /** How long to search after TargetLost before giving up */
+  search_duration_s: number;
+}
+
+/** Partial update applied on top of the running servo config */
+export type ServoConfigUpdate = Partial<ServoConfig> & { entity_id?: string };
+
+/** Load-adaptive detector scheduling policy, published whenever it changes */
+export interface DetectionPolicy {
+  entity_id?: string;
+  target_fps: number;
+  measured_fps: number;
+  /** Run inference on every Nth frame (1 = every frame) */
+  frame_skip: number;
+  /** Input downscale factor applied before inference (1.0 = native) */
+  input_scale: number;
+  inference_ms: number;
+  timestamp: number;
+}
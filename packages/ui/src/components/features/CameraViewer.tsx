@@ -1,19 +1,24 @@
 import {useEffect, useRef, useState} from "react";
 import {
   Activity,
+  Aperture,
   Camera,
   ChevronDown,
   ChevronLeft,
   ChevronRight,
   ChevronUp,
+  Circle,
   Crosshair,
   Eye,
   EyeOff,
+  Flag,
+  Gauge,
   Layers,
   Maximize2,
   Minimize2,
   Power,
   Scan,
+  SwitchCamera,
   Target,
   Volume2,
   VolumeX,
@@ -21,27 +26,55 @@ import {
   XCircle
 } from "lucide-react";
 import {Socket} from "socket.io-client";
-import type {DetectionFrame, TrackingTelemetry, WebTrackingCommand} from "@robo-fleet/shared/types";
+import type {
+  CameraInfo,
+  CameraList,
+  DetectionFrame,
+  DetectionPolicy,
+  PhotoCaptured,
+  RecordingCommand,
+  RecordingStatus,
+  ResolutionTier,
+  StreamSettings,
+  StreamSettingsAck,
+  TelemetryTopic,
+  TrackingTelemetry,
+  WebTrackingCommand
+} from "@robo-fleet/shared/types";
 import {getClassColor} from "@robo-fleet/shared/constants";
 
 type ViewMode = "camera" | "camera_with_detections" | "detections_only";
 
-interface JPEGVideoFrame {
+interface EncodedVideoFrame {
   timestamp: number;
   frame_id: number;
   width: number;
   height: number;
-  codec: "jpeg";
-  data: number[]; // JPEG image as byte array
+  codec: "jpeg" | "h264";
+  camera_id?: string; // absent on single-camera rovers
+  is_keyframe?: boolean; // h264 only — IDR access unit with SPS/PPS
+  data: number[]; // JPEG image or Annex B NAL units as byte array
 }
 
+// Bandwidth presets sent as target_fps / jpeg_quality / resolution_tier — server clamps and acks
+const QUALITY_PRESETS: Record<ResolutionTier, { target_fps: number; jpeg_quality: number }> = {
+  low: { target_fps: 10, jpeg_quality: 50 },
+  medium: { target_fps: 15, jpeg_quality: 70 },
+  high: { target_fps: 30, jpeg_quality: 80 },
+  native: { target_fps: 30, jpeg_quality: 90 },
+};
+const QUALITY_TIERS: ResolutionTier[] = ["low", "medium", "high", "native"];
+
+// Constrained Baseline, level 3.1 — what the rover encoder emits
+const H264_CODEC_STRING = "avc1.42E01F";
+
 interface AudioFrame {
   timestamp: number;
   frame_id: number;
   sample_rate: number;
   channels: number;
-  format: string; // "s16le", "f32le", etc.
-  data: number[]; // PCM audio data as byte array
+  format: string; // "s16le", "f32le", "opus", etc.
+  data: number[]; // PCM samples, or one Opus packet, as byte array
 }
 
 interface StreamStats {
@@ -58,12 +91,15 @@ interface StreamStats {
 interface CameraViewerProps {
   isConnected: boolean;
   socket: Socket | null;
+  /** Base for relative URLs served by web_bridge (e.g. photo downloads) */
+  serverUrl?: string;
   onClose?: () => void;
 }
 
 export const CameraViewer: React.FC<CameraViewerProps> = ({
   isConnected,
   socket,
+  serverUrl,
   onClose,
 }) => {
   const canvasRef = useRef<HTMLCanvasElement>(null);
@@ -75,9 +111,15 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
   const [cameraEnabled, setCameraEnabled] = useState(true);
   const [isFullscreen, setIsFullscreen] = useState(false);
   const [viewMode, setViewMode] = useState<ViewMode>("camera_with_detections");
+  const [qualityTier, setQualityTier] = useState<ResolutionTier>("high");
+  const [cameras, setCameras] = useState<CameraInfo[]>([]);
+  const [activeCameraId, setActiveCameraId] = useState<string | null>(null);
+  const pendingPhotoRef = useRef<string | null>(null);
+  const [recordingStatus, setRecordingStatus] = useState<RecordingStatus | null>(null);
   const [latestDetections, setLatestDetections] = useState<DetectionFrame | null>(null);
   const [trackedDetections, setTrackedDetections] = useState<DetectionFrame | null>(null);
   const [trackingTelemetry, setTrackingTelemetry] = useState<TrackingTelemetry | null>(null);
+  const [detectionPolicy, setDetectionPolicy] = useState<DetectionPolicy | null>(null);
   const [showStats, setShowStats] = useState(true);
   const [showDetections, setShowDetections] = useState(true);
   const [showTracking, setShowTracking] = useState(true);
@@ -97,6 +139,10 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
   const lastFpsUpdateRef = useRef(Date.now());
   const bytesReceivedRef = useRef(0);
   const detectionCountRef = useRef(0);
+  const videoDecoderRef = useRef<VideoDecoder | null>(null);
+  const awaitingKeyframeRef = useRef(false); // set once a delta frame is dropped
+  const audioDecoderRef = useRef<AudioDecoder | null>(null);
+  const renderFrameRef = useRef<((source: CanvasImageSource, width: number, height: number) => void) | null>(null);
   const lastDetectionFpsUpdateRef = useRef(Date.now());
 
   // Audio playback references
@@ -112,7 +158,7 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
   // Draw detection bounding boxes on canvas
   const drawDetections = (ctx: CanvasRenderingContext2D, detections: DetectionFrame, canvasWidth: number, canvasHeight: number, overlay: boolean = true) => {
     detections.detections.forEach((detection) => {
-      const { bbox, class_name, confidence, tracking_id } = detection;
+      const { bbox, class_name, confidence, tracking_id, depth_m } = detection;
 
       // Convert normalized coordinates to pixel coordinates
       const x1 = bbox.x1 * canvasWidth;
@@ -180,7 +226,8 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
       }
 
       // Draw label background
-      const label = `${class_name} ${(confidence * 100).toFixed(0)}%`;
+      const depthSuffix = depth_m !== undefined ? ` ${depth_m.toFixed(2)}m` : "";
+      const label = `${class_name} ${(confidence * 100).toFixed(0)}%${depthSuffix}`;
       ctx.font = overlay ? "16px Arial" : "18px Arial";
       const textMetrics = ctx.measureText(label);
       const textHeight = overlay ? 20 : 24;
@@ -236,7 +283,117 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
   useEffect(() => {
     if (!socket || !streamEnabled) return;
 
-    const handleVideoFrame = (frame: JPEGVideoFrame) => {
+    // Draw a decoded frame (JPEG image or WebCodecs VideoFrame) with overlays
+    const renderFrame = (source: CanvasImageSource, width: number, height: number) => {
+      if (canvasRef.current) {
+        const ctx = canvasRef.current.getContext('2d');
+        if (ctx) {
+          // Set canvas size to match frame
+          if (canvasRef.current.width !== width ||
+              canvasRef.current.height !== height) {
+            canvasRef.current.width = width;
+            canvasRef.current.height = height;
+          }
+
+          // Render based on view mode
+          if (viewMode === "detections_only") {
+            // Detections-only view: show only bounding boxes on dark background
+            const detectionsToShow = trackedDetections || latestDetections;
+            if (detectionsToShow && detectionsToShow.detections.length > 0) {
+              drawDetectionsOnly(ctx, detectionsToShow, width, height);
+            } else {
+              // No detections - show empty grid
+              ctx.fillStyle = "#1a1a1a";
+              ctx.fillRect(0, 0, width, height);
+              ctx.fillStyle = "#666666";
+              ctx.font = "20px Arial";
+              ctx.textAlign = "center";
+              ctx.fillText("No objects detected", width / 2, height / 2);
+              ctx.textAlign = "left";
+            }
+          } else {
+            // Camera view or camera + detections view
+            ctx.drawImage(source, 0, 0, width, height);
+
+            // Draw detections overlay if view mode includes detections
+            // Prefer tracked detections (with IDs) over raw detections
+            if (viewMode === "camera_with_detections") {
+              const detectionsToShow = trackedDetections || latestDetections;
+              if (detectionsToShow) {
+                drawDetections(ctx, detectionsToShow, width, height, true);
+              }
+            }
+          }
+        }
+      }
+
+      // Update FPS counter
+      frameCountRef.current++;
+      const now = Date.now();
+      if (now - lastFpsUpdateRef.current >= 1000) {
+        const elapsed = (now - lastFpsUpdateRef.current) / 1000;
+        const fps = frameCountRef.current / elapsed;
+        const bitrate = (bytesReceivedRef.current * 8) / elapsed / 1000; // kbps
+
+        setStats(prev => ({
+          ...prev,
+          video_fps: fps,
+          video_bitrate_kbps: bitrate
+        }));
+
+        frameCountRef.current = 0;
+        bytesReceivedRef.current = 0;
+        lastFpsUpdateRef.current = now;
+      }
+    };
+    renderFrameRef.current = renderFrame;
+
+    // Feed H.264 access units to a WebCodecs decoder; output is drawn via renderFrameRef
+    const decodeH264 = (frame: EncodedVideoFrame, data: Uint8Array) => {
+      if (typeof VideoDecoder === "undefined") {
+        console.warn("H.264 stream received but WebCodecs is not available in this browser");
+        return;
+      }
+
+      let decoder = videoDecoderRef.current;
+      if (!decoder || decoder.state === "closed") {
+        // Decoder can only start on a keyframe
+        if (!frame.is_keyframe) return;
+
+        decoder = new VideoDecoder({
+          output: (videoFrame) => {
+            renderFrameRef.current?.(videoFrame, videoFrame.displayWidth, videoFrame.displayHeight);
+            videoFrame.close();
+          },
+          error: (error) => {
+            console.error("❌ H.264 decoder error:", error);
+            videoDecoderRef.current = null;
+          },
+        });
+        decoder.configure({ codec: H264_CODEC_STRING, optimizeForLatency: true });
+        videoDecoderRef.current = decoder;
+      }
+
+      // Drop frames instead of building latency when the decoder falls behind.
+      // Later deltas reference the dropped one, so skip the rest of the GOP.
+      if (!frame.is_keyframe) {
+        if (awaitingKeyframeRef.current) return;
+        if (decoder.decodeQueueSize > 5) {
+          awaitingKeyframeRef.current = true;
+          return;
+        }
+      } else {
+        awaitingKeyframeRef.current = false;
+      }
+
+      decoder.decode(new EncodedVideoChunk({
+        type: frame.is_keyframe ? "key" : "delta",
+        timestamp: frame.timestamp * 1000, // ms → µs
+        data,
+      }));
+    };
+
+    const handleVideoFrame = (frame: EncodedVideoFrame) => {
       setStats((prev) => ({
         ...prev,
         video_frames_received: prev.video_frames_received + 1,
@@ -244,81 +401,30 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
 
       if (!canvasRef.current || !videoEnabled) return;
 
+      // Server may still be flushing frames from a previously selected camera
+      if (activeCameraId && frame.camera_id && frame.camera_id !== activeCameraId) return;
+
       try {
         // Convert number array to Uint8Array
-        const jpegData = new Uint8Array(frame.data);
-        bytesReceivedRef.current += jpegData.length;
+        const frameData = new Uint8Array(frame.data);
+        bytesReceivedRef.current += frameData.length;
+
+        if (frame.codec === "h264") {
+          decodeH264(frame, frameData);
+          return;
+        }
 
         // Create blob from JPEG data
-        const blob = new Blob([jpegData], { type: 'image/jpeg' });
+        const blob = new Blob([frameData], { type: 'image/jpeg' });
         const url = URL.createObjectURL(blob);
 
         // Load and render JPEG to canvas
         const img = imgRef.current;
         img.onload = () => {
-          if (canvasRef.current) {
-            const ctx = canvasRef.current.getContext('2d');
-            if (ctx) {
-              // Set canvas size to match frame
-              if (canvasRef.current.width !== frame.width ||
-                  canvasRef.current.height !== frame.height) {
-                canvasRef.current.width = frame.width;
-                canvasRef.current.height = frame.height;
-              }
-
-              // Render based on view mode
-              if (viewMode === "detections_only") {
-                // Detections-only view: show only bounding boxes on dark background
-                const detectionsToShow = trackedDetections || latestDetections;
-                if (detectionsToShow && detectionsToShow.detections.length > 0) {
-                  drawDetectionsOnly(ctx, detectionsToShow, frame.width, frame.height);
-                } else {
-                  // No detections - show empty grid
-                  ctx.fillStyle = "#1a1a1a";
-                  ctx.fillRect(0, 0, frame.width, frame.height);
-                  ctx.fillStyle = "#666666";
-                  ctx.font = "20px Arial";
-                  ctx.textAlign = "center";
-                  ctx.fillText("No objects detected", frame.width / 2, frame.height / 2);
-                  ctx.textAlign = "left";
-                }
-              } else {
-                // Camera view or camera + detections view
-                ctx.drawImage(img, 0, 0, frame.width, frame.height);
-
-                // Draw detections overlay if view mode includes detections
-                // Prefer tracked detections (with IDs) over raw detections
-                if (viewMode === "camera_with_detections") {
-                  const detectionsToShow = trackedDetections || latestDetections;
-                  if (detectionsToShow) {
-                    drawDetections(ctx, detectionsToShow, frame.width, frame.height, true);
-                  }
-                }
-              }
-            }
-          }
+          renderFrame(img, frame.width, frame.height);
 
           // Clean up blob URL
           URL.revokeObjectURL(url);
-
-          // Update FPS counter
-          frameCountRef.current++;
-          const now = Date.now();
-          if (now - lastFpsUpdateRef.current >= 1000) {
-            const elapsed = (now - lastFpsUpdateRef.current) / 1000;
-            const fps = frameCountRef.current / elapsed;
-            const bitrate = (bytesReceivedRef.current * 8) / elapsed / 1000; // kbps
-
-            setStats(prev => ({
-              ...prev,
-              video_fps: fps,
-              video_bitrate_kbps: bitrate
-            }));
-
-            frameCountRef.current = 0;
-            bytesReceivedRef.current = 0;
-            lastFpsUpdateRef.current = now;
-          }
         };
 
         img.onerror = () => {
@@ -337,7 +443,90 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
     return () => {
       socket.off("video_frame", handleVideoFrame);
     };
-  }, [socket, streamEnabled, videoEnabled, viewMode, latestDetections, trackedDetections, trackingTelemetry]);
+  }, [socket, streamEnabled, videoEnabled, viewMode, latestDetections, trackedDetections, trackingTelemetry, activeCameraId]);
+
+  // Track the rover's camera set; default to the first camera
+  useEffect(() => {
+    if (!socket) return;
+
+    const handleCameraList = (list: CameraList) => {
+      setCameras(list.cameras);
+      setActiveCameraId((prev) =>
+        prev && list.cameras.some((c) => c.camera_id === prev) ? prev : list.cameras[0]?.camera_id ?? null
+      );
+    };
+
+    // Full-resolution still — download it in the browser
+    const handlePhotoCaptured = (photo: PhotoCaptured) => {
+      if (photo.request_id !== pendingPhotoRef.current) return;
+      pendingPhotoRef.current = null;
+
+      if (!photo.success) {
+        console.error("❌ Snapshot failed:", photo.error);
+        return;
+      }
+
+      const href = photo.url
+        ? new URL(photo.url, serverUrl ?? window.location.href).href
+        : (photo.data ? URL.createObjectURL(new Blob([new Uint8Array(photo.data)], { type: "image/jpeg" })) : null);
+      if (!href) return;
+
+      const link = document.createElement("a");
+      link.href = href;
+      link.download = `${photo.metadata?.entity_id ?? "rover"}_${photo.metadata?.timestamp ?? Date.now()}.jpg`;
+      link.click();
+
+      // Revoking synchronously can cancel the download in Firefox/Safari
+      if (!photo.url) setTimeout(() => URL.revokeObjectURL(href), 1000);
+      console.log(`Snapshot saved on rover: ${photo.stored_path ?? "n/a"}`);
+    };
+
+    const handleRecordingStatus = (status: RecordingStatus) => {
+      setRecordingStatus(status);
+    };
+
+    socket.on("camera_list", handleCameraList);
+    socket.on("photo_captured", handlePhotoCaptured);
+    socket.on("recording_status", handleRecordingStatus);
+
+    return () => {
+      socket.off("camera_list", handleCameraList);
+      socket.off("photo_captured", handlePhotoCaptured);
+      socket.off("recording_status", handleRecordingStatus);
+    };
+  }, [socket, serverUrl]);
+
+  // Camera selection is per-client server state — re-apply it after a reconnect
+  useEffect(() => {
+    if (!socket || !activeCameraId) return;
+
+    const restoreCameraSelection = () => {
+      socket.emit("camera_select", { camera_ids: [activeCameraId] });
+    };
+
+    socket.on("connect", restoreCameraSelection);
+    return () => {
+      socket.off("connect", restoreCameraSelection);
+    };
+  }, [socket, activeCameraId]);
+
+  // Release the H.264 decoder when the stream stops — next session restarts on a keyframe
+  useEffect(() => {
+    if (streamEnabled) return;
+
+    if (videoDecoderRef.current && videoDecoderRef.current.state !== "closed") {
+      videoDecoderRef.current.close();
+    }
+    videoDecoderRef.current = null;
+  }, [streamEnabled]);
+
+  // Unmounting while streaming skips the effect above — don't leak a (possibly hardware) decoder
+  useEffect(() => () => {
+    if (videoDecoderRef.current && videoDecoderRef.current.state !== "closed") {
+      videoDecoderRef.current.close();
+    }
+    videoDecoderRef.current = null;
+  }, []);
 
   // Initialize Audio Context
   useEffect(() => {
@@ -392,6 +581,86 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
   useEffect(() => {
     if (!socket || !streamEnabled || !audioEnabled) return;
 
+    // Queue audio buffer for playback and start once enough is buffered
+    const enqueueAudioBuffer = (audioBuffer: AudioBuffer) => {
+      if (!audioContextRef.current) return;
+      const audioContext = audioContextRef.current;
+
+      // Queue audio buffer for playback (with max queue size limit)
+      if (audioQueueRef.current.length < maxBufferQueueSize.current) {
+        audioQueueRef.current.push(audioBuffer);
+      } else {
+        // Drop oldest buffer if queue is full to prevent excessive latency
+        audioQueueRef.current.shift();
+        audioQueueRef.current.push(audioBuffer);
+        console.warn("Audio queue full, dropping oldest buffer");
+      }
+
+      // Update buffer stats
+      const bufferDuration = audioQueueRef.current.reduce((sum, buf) => sum + buf.duration, 0);
+      setStats(prev => ({
+        ...prev,
+        audio_buffer_ms: bufferDuration * 1000
+      }));
+
+      // Start playback only if we have enough buffers to prevent underruns
+      if (!isPlayingRef.current && audioQueueRef.current.length >= audioBufferThreshold.current) {
+        console.log(`🔊 Starting audio playback with ${audioQueueRef.current.length} buffers (${bufferDuration.toFixed(3)}s)`);
+        isPlayingRef.current = true;
+        // Initialize next play time with a small delay to build buffer
+        nextPlayTimeRef.current = audioContext.currentTime + 0.1;
+        scheduleNextAudioBuffer();
+      }
+    };
+
+    // Feed Opus packets to a WebCodecs decoder; decoded PCM joins the same playback queue
+    const decodeOpus = (frame: AudioFrame) => {
+      if (typeof AudioDecoder === "undefined") {
+        console.warn("Opus audio received but WebCodecs is not available in this browser");
+        return;
+      }
+
+      let decoder = audioDecoderRef.current;
+      if (!decoder || decoder.state === "closed") {
+        decoder = new AudioDecoder({
+          output: (audioData) => {
+            const audioContext = audioContextRef.current;
+            if (audioContext) {
+              const audioBuffer = audioContext.createBuffer(
+                audioData.numberOfChannels,
+                audioData.numberOfFrames,
+                audioData.sampleRate
+              );
+              for (let channel = 0; channel < audioData.numberOfChannels; channel++) {
+                audioData.copyTo(audioBuffer.getChannelData(channel), {
+                  planeIndex: channel,
+                  format: "f32-planar",
+                });
+              }
+              enqueueAudioBuffer(audioBuffer);
+            }
+            audioData.close();
+          },
+          error: (error) => {
+            console.error("❌ Opus decoder error:", error);
+            audioDecoderRef.current = null;
+          },
+        });
+        decoder.configure({
+          codec: "opus",
+          sampleRate: frame.sample_rate,
+          numberOfChannels: frame.channels,
+        });
+        audioDecoderRef.current = decoder;
+      }
+
+      decoder.decode(new EncodedAudioChunk({
+        type: "key", // every Opus packet is independently decodable
+        timestamp: frame.timestamp * 1000, // ms → µs
+        data: new Uint8Array(frame.data),
+      }));
+    };
+
     const handleAudioFrame = async (frame: AudioFrame) => {
       setStats((prev) => ({
         ...prev,
@@ -403,6 +672,11 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
         return;
       }
 
+      if (frame.format === "opus") {
+        decodeOpus(frame);
+        return;
+      }
+
       try {
         const audioContext = audioContextRef.current;
         const pcmData = new Uint8Array(frame.data);
@@ -480,31 +754,7 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
           }
         }
 
-        // Queue audio buffer for playback (with max queue size limit)
-        if (audioQueueRef.current.length < maxBufferQueueSize.current) {
-          audioQueueRef.current.push(audioBuffer);
-        } else {
-          // Drop oldest buffer if queue is full to prevent excessive latency
-          audioQueueRef.current.shift();
-          audioQueueRef.current.push(audioBuffer);
-          console.warn("Audio queue full, dropping oldest buffer");
-        }
-
-        // Update buffer stats
-        const bufferDuration = audioQueueRef.current.reduce((sum, buf) => sum + buf.duration, 0);
-        setStats(prev => ({
-          ...prev,
-          audio_buffer_ms: bufferDuration * 1000
-        }));
-
-        // Start playback only if we have enough buffers to prevent underruns
-        if (!isPlayingRef.current && audioQueueRef.current.length >= audioBufferThreshold.current) {
-          console.log(`🔊 Starting audio playback with ${audioQueueRef.current.length} buffers (${bufferDuration.toFixed(3)}s)`);
-          isPlayingRef.current = true;
-          // Initialize next play time with a small delay to build buffer
-          nextPlayTimeRef.current = audioContext.currentTime + 0.1;
-          scheduleNextAudioBuffer();
-        }
+        enqueueAudioBuffer(audioBuffer);
 
       } catch (error) {
         console.error("Error processing audio frame:", error, frame);
@@ -583,6 +833,11 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
     return () => {
       socket.off("audio_frame", handleAudioFrame);
 
+      if (audioDecoderRef.current && audioDecoderRef.current.state !== "closed") {
+        audioDecoderRef.current.close();
+      }
+      audioDecoderRef.current = null;
+
       // Clear audio queue on cleanup
       audioQueueRef.current = [];
       isPlayingRef.current = false;
@@ -628,23 +883,99 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
       setTrackingTelemetry(telemetry);
     };
 
+    const handleDetectionPolicy = (policy: DetectionPolicy) => {
+      setDetectionPolicy(policy);
+    };
+
     socket.on("detections", handleDetections);
     socket.on("tracked_detections", handleTrackedDetections);
     socket.on("tracking_telemetry", handleTrackingTelemetry);
+    socket.on("detection_policy", handleDetectionPolicy);
 
     return () => {
       socket.off("detections", handleDetections);
       socket.off("tracked_detections", handleTrackedDetections);
       socket.off("tracking_telemetry", handleTrackingTelemetry);
+      socket.off("detection_policy", handleDetectionPolicy);
     };
   }, [socket, streamEnabled]);
 
+  // Only ask the bridge for detection frames while they are drawn
+  useEffect(() => {
+    if (!socket) return;
+
+    const topics: TelemetryTopic[] = ["detections", "tracked_detections"];
+    const wanted = streamEnabled && viewMode !== "camera";
+    const sendSubscription = () => {
+      socket.emit("subscribe", {
+        subscribe: wanted ? topics : [],
+        unsubscribe: wanted ? [] : topics,
+      });
+    };
+
+    sendSubscription();
+    // A reconnect gets fresh server-side client state with every topic subscribed
+    socket.on("connect", sendSubscription);
+    return () => {
+      socket.off("connect", sendSubscription);
+    };
+  }, [socket, streamEnabled, viewMode]);
+
+  // Per-client stream preferences — server only applies the fields present
+  const sendStreamSettings = (settings: StreamSettings) => {
+    if (!socket) return;
+    socket.emit("stream_settings", settings);
+  };
+
+  // Reflect the tier the server actually applied
+  useEffect(() => {
+    if (!socket) return;
+
+    const handleStreamSettingsAck = (ack: StreamSettingsAck) => {
+      setQualityTier(ack.resolution_tier);
+    };
+
+    socket.on("stream_settings_ack", handleStreamSettingsAck);
+    return () => {
+      socket.off("stream_settings_ack", handleStreamSettingsAck);
+    };
+  }, [socket]);
+
+  // Server-side stream settings reset on reconnect — restore this client's choices
+  useEffect(() => {
+    if (!socket) return;
+
+    const restoreStreamSettings = () => {
+      socket.emit("stream_settings", {
+        video_enabled: streamEnabled && videoEnabled,
+        audio_enabled: streamEnabled && audioEnabled,
+        resolution_tier: qualityTier,
+        ...QUALITY_PRESETS[qualityTier],
+      });
+    };
+
+    socket.on("connect", restoreStreamSettings);
+    return () => {
+      socket.off("connect", restoreStreamSettings);
+    };
+  }, [socket, streamEnabled, videoEnabled, audioEnabled, qualityTier]);
+
+  const cycleQuality = () => {
+    const next = QUALITY_TIERS[(QUALITY_TIERS.indexOf(qualityTier) + 1) % QUALITY_TIERS.length]!;
+    setQualityTier(next);
+    sendStreamSettings({ resolution_tier: next, ...QUALITY_PRESETS[next] });
+  };
+
   // Stream control
   const toggleStream = () => {
     if (!socket) return;
 
     const newState = !streamEnabled;
     setStreamEnabled(newState);
+    sendStreamSettings({
+      video_enabled: newState && videoEnabled,
+      audio_enabled: newState && audioEnabled,
+    });
 
     console.log(newState ? "Stream started" : "Stream stopped");
   };
@@ -652,6 +983,7 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
   const toggleVideo = () => {
     const newState = !videoEnabled;
     setVideoEnabled(newState);
+    sendStreamSettings({ video_enabled: streamEnabled && newState });
   };
 
   const toggleAudio = () => {
@@ -659,6 +991,7 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
 
     const newState = !audioEnabled;
     setAudioEnabled(newState);
+    sendStreamSettings({ audio_enabled: streamEnabled && newState });
 
     socket.emit("audio_control", {
       command: newState ? "start" : "stop"
@@ -691,6 +1024,51 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
     console.log(newState ? "Camera enabled" : "Camera disabled");
   };
 
+  const capturePhoto = () => {
+    if (!socket) return;
+
+    const requestId = `${Date.now()}-${Math.random().toString(36).slice(2, 8)}`;
+    pendingPhotoRef.current = requestId;
+    socket.emit("capture_photo", {
+      request_id: requestId,
+      camera_id: activeCameraId ?? undefined,
+    });
+  };
+
+  const sendRecordingCommand = (command: RecordingCommand) => {
+    if (!socket) return;
+    socket.emit("recording_command", command);
+  };
+
+  const toggleRecording = () => {
+    if (recordingStatus?.is_recording) {
+      sendRecordingCommand({ command: "stop_recording" });
+    } else {
+      sendRecordingCommand({ command: "start_recording", include_audio: audioEnabled });
+    }
+  };
+
+  const markRecordingEvent = () => {
+    sendRecordingCommand({ command: "mark_event", label: `operator_mark_${new Date().toISOString()}` });
+  };
+
+  const cycleCamera = () => {
+    if (!socket || cameras.length < 2) return;
+
+    const currentIndex = cameras.findIndex((c) => c.camera_id === activeCameraId);
+    const next = cameras[(currentIndex + 1) % cameras.length]!;
+    setActiveCameraId(next.camera_id);
+    socket.emit("camera_select", { camera_ids: [next.camera_id] });
+
+    // Decoder state belongs to the previous camera's stream
+    if (videoDecoderRef.current && videoDecoderRef.current.state !== "closed") {
+      videoDecoderRef.current.close();
+    }
+    videoDecoderRef.current = null;
+
+    console.log(`Camera: ${next.name}`);
+  };
+
   const cycleViewMode = () => {
     const modes: ViewMode[] = ["camera", "camera_with_detections", "detections_only"];
     const currentIndex = modes.indexOf(viewMode);
@@ -718,6 +1096,9 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
   };
 
   const pipelineState = trackingTelemetry?.state ?? "Disabled";
+  // Target selection needs the tracker running, not just the detector
+  const canSelectTarget =
+    pipelineState === "Enabled" || pipelineState === "Tracking" || pipelineState === "TargetLost";
 
   // Tracking control functions
   const sendTrackingCommand = (command: WebTrackingCommand) => {
@@ -751,6 +1132,15 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
     console.log(`Selected tracking target ID: ${trackingId}`);
   };
 
+  // Resolved by the tracker against the ordering of its last tracked_detections
+  const selectTrackingTargetByIndex = (detectionIndex: number) => {
+    sendTrackingCommand({
+      command_type: "select_target",
+      detection_index: detectionIndex,
+    });
+    console.log(`Selected tracking target at index: ${detectionIndex}`);
+  };
+
   const clearTrackingTarget = () => {
     sendTrackingCommand({
       command_type: "clear_target",
@@ -758,24 +1148,20 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
     console.log("Cleared tracking target");
   };
 
-  // Handle canvas click for target selection
+  // Handle canvas click for target selection — the tracker resolves the point
+  // to the smallest track bbox containing it
   const handleCanvasClick = (event: React.MouseEvent<HTMLCanvasElement>) => {
-    if (!canvasRef.current || !trackedDetections) return;
+    if (!canvasRef.current || !canSelectTarget) return;
 
     const rect = canvasRef.current.getBoundingClientRect();
     const x = ((event.clientX - rect.left) / rect.width);
     const y = ((event.clientY - rect.top) / rect.height);
 
-    // Find clicked detection
-    for (const detection of trackedDetections.detections) {
-      const { bbox, tracking_id } = detection;
-      if (x >= bbox.x1 && x <= bbox.x2 && y >= bbox.y1 && y <= bbox.y2) {
-        if (tracking_id !== undefined) {
-          selectTrackingTarget(tracking_id);
-          return;
-        }
-      }
-    }
+    sendTrackingCommand({
+      command_type: "select_target_at_point",
+      point: { x, y },
+    });
+    console.log(`Select target at (${x.toFixed(3)}, ${y.toFixed(3)})`);
   };
 
   return (
@@ -783,7 +1169,7 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
         {/* Canvas for rendering JPEG frames */}
         <canvas
             ref={canvasRef}
-            className="w-full h-full object-contain cursor-crosshair"
+            className={`w-full h-full object-contain ${canSelectTarget ? "cursor-crosshair" : ""}`}
             style={{ imageRendering: 'auto' }}
             onClick={handleCanvasClick}
         />
@@ -857,6 +1243,60 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
                   {audioEnabled ? <Volume2 className="w-5 h-5 text-green-400" /> : <VolumeX className="w-5 h-5 text-red-400" />}
                 </button>
 
+                <button
+                    onClick={cycleQuality}
+                    className="p-2 bg-white/10 hover:bg-white/20 rounded-lg backdrop-blur-md transition group relative"
+                    title="Stream Quality"
+                    disabled={!streamEnabled}
+                >
+                  <Gauge className={`w-5 h-5 ${qualityTier === "low" ? "text-yellow-400" : ""}`} />
+                  <span className="absolute right-full mr-2 px-2 py-1 bg-black/80 rounded text-xs whitespace-nowrap opacity-0 group-hover:opacity-100 transition pointer-events-none">
+                    {qualityTier} · {QUALITY_PRESETS[qualityTier].target_fps}fps · q{QUALITY_PRESETS[qualityTier].jpeg_quality}
+                  </span>
+                </button>
+
+                <button
+                    onClick={capturePhoto}
+                    className="p-2 bg-white/10 hover:bg-white/20 rounded-lg backdrop-blur-md transition"
+                    title="Capture Photo"
+                    disabled={!isConnected || !cameraEnabled}
+                >
+                  <Aperture className="w-5 h-5" />
+                </button>
+
+                <button
+                    onClick={toggleRecording}
+                    className="p-2 bg-white/10 hover:bg-white/20 rounded-lg backdrop-blur-md transition"
+                    title={recordingStatus?.is_recording ? "Stop On-Rover Recording" : "Start On-Rover Recording"}
+                    disabled={!isConnected}
+                >
+                  <Circle className={`w-5 h-5 ${recordingStatus?.is_recording ? "text-red-500 fill-red-500 animate-pulse" : ""}`} />
+                </button>
+
+                {recordingStatus?.is_recording && (
+                  <button
+                      onClick={markRecordingEvent}
+                      className="p-2 bg-white/10 hover:bg-white/20 rounded-lg backdrop-blur-md transition"
+                      title="Mark Event in Recording"
+                  >
+                    <Flag className="w-5 h-5 text-yellow-400" />
+                  </button>
+                )}
+
+                {cameras.length > 1 && (
+                  <button
+                      onClick={cycleCamera}
+                      className="p-2 bg-white/10 hover:bg-white/20 rounded-lg backdrop-blur-md transition group relative"
+                      title="Switch Camera"
+                      disabled={!isConnected}
+                  >
+                    <SwitchCamera className="w-5 h-5" />
+                    <span className="absolute right-full mr-2 px-2 py-1 bg-black/80 rounded text-xs whitespace-nowrap opacity-0 group-hover:opacity-100 transition pointer-events-none">
+                      {cameras.find((c) => c.camera_id === activeCameraId)?.name ?? "Camera"}
+                    </span>
+                  </button>
+                )}
+
                 <button
                     onClick={cycleViewMode}
                     className="p-2 bg-white/10 hover:bg-white/20 rounded-lg backdrop-blur-md transition group relative"
@@ -991,6 +1431,15 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
 
                         <span className="text-gray-400 col-start-1">Objects:</span>
                         <span className="font-mono text-purple-300">{stats.total_objects_detected}</span>
+
+                        {detectionPolicy && (detectionPolicy.frame_skip > 1 || detectionPolicy.input_scale < 1) && (
+                          <>
+                            <span className="text-gray-400 col-start-1">Policy:</span>
+                            <span className="font-mono text-yellow-300">
+                              1/{detectionPolicy.frame_skip} @ {(detectionPolicy.input_scale * 100).toFixed(0)}%
+                            </span>
+                          </>
+                        )}
                       </>
                     )}
 
@@ -1093,7 +1542,9 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
                       </>
                     )}
                     <div className="mt-2 text-gray-400 italic text-xs">
-                      Click on detected objects to track
+                      {canSelectTarget
+                        ? "Click on detected objects to track"
+                        : "Enable tracking to select a target"}
                     </div>
                   </div>
                 </div>
@@ -1114,14 +1565,29 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
                   <div className="space-y-1 max-h-48 overflow-y-auto [&::-webkit-scrollbar]:w-1 [&::-webkit-scrollbar-thumb]:bg-white/20 [&::-webkit-scrollbar-thumb]:rounded">
                     {(trackedDetections || latestDetections)?.detections?.map((detection, index) => {
                       const isTracked = trackingTelemetry?.target?.tracking_id === detection.tracking_id;
+                      // Index selection is resolved against tracked_detections, so raw detections can't be picked
+                      const selectable =
+                        canSelectTarget && (detection.tracking_id !== undefined || trackedDetections !== null);
                       return (
                         <div
                           key={index}
-                          className={`flex items-center justify-between gap-2 py-1 px-2 rounded cursor-pointer transition ${
-                            isTracked ? "bg-green-500/30" : "bg-white/10 hover:bg-white/20"
+                          className={`flex items-center justify-between gap-2 py-1 px-2 rounded transition ${
+                            isTracked
+                              ? "bg-green-500/30"
+                              : selectable
+                                ? "bg-white/10 hover:bg-white/20 cursor-pointer"
+                                : "bg-white/5 opacity-50 cursor-not-allowed"
                           }`}
                           style={{ borderLeft: `3px solid ${isTracked ? "#00ff00" : getClassColor(detection.class_name)}` }}
-                          onClick={() => detection.tracking_id && selectTrackingTarget(detection.tracking_id)}
+                          title={selectable ? undefined : "Enable tracking to select this object"}
+                          onClick={() => {
+                            if (!selectable) return;
+                            if (detection.tracking_id !== undefined) {
+                              selectTrackingTarget(detection.tracking_id);
+                            } else {
+                              selectTrackingTargetByIndex(index);
+                            }
+                          }}
                         >
                           <div className="flex items-center gap-2">
                             {detection.tracking_id !== undefined && (
@@ -1131,7 +1597,12 @@ export const CameraViewer: React.FC<CameraViewerProps> = ({
                             )}
                             <span className="font-medium">{detection.class_name}</span>
                           </div>
-                          <span className="text-gray-300">{(detection.confidence * 100).toFixed(0)}%</span>
+                          <span className="text-gray-300">
+                            {detection.depth_m !== undefined && (
+                              <span className="font-mono text-cyan-300 mr-2">{detection.depth_m.toFixed(2)}m</span>
+                            )}
+                            {(detection.confidence * 100).toFixed(0)}%
+                          </span>
                         </div>
                       );
                     })}
@@ -0,0 +1,79 @@
+import React from "react";
+import { AlertTriangle, BellRing, Check, Info } from "lucide-react";
+import type { AlertSeverity, OperatorAlert } from "@robo-fleet/shared/types";
+
+export interface AlertPanelProps {
+  alerts: OperatorAlert[];
+  onAcknowledge: (alertId: string) => void;
+  disabled?: boolean;
+  className?: string;
+}
+
+const SEVERITY_STYLES: Record<AlertSeverity, { border: string; text: string; icon: typeof Info }> = {
+  critical: { border: "border-syntax-red", text: "text-syntax-red", icon: AlertTriangle },
+  warning: { border: "border-syntax-yellow", text: "text-syntax-yellow", icon: BellRing },
+  info: { border: "border-syntax-cyan", text: "text-syntax-cyan", icon: Info },
+};
+
+export const AlertPanel: React.FC<AlertPanelProps> = ({
+  alerts,
+  onAcknowledge,
+  disabled = false,
+  className = "",
+}) => {
+  // Resolved alerts drop off; unacknowledged first, then newest first
+  const active = alerts
+    .filter((alert) => alert.state !== "resolved")
+    .sort((a, b) =>
+      a.state === b.state ? b.raised_at - a.raised_at : a.state === "raised" ? -1 : 1,
+    );
+
+  if (active.length === 0) return null;
+
+  return (
+    <div className={`glass-card rounded-lg shadow-2xl p-3 md:p-4 space-y-2 ${className}`}>
+      <div className="flex items-center gap-2 text-sm font-mono font-bold text-syntax-red">
+        <BellRing className="w-4 h-4" />
+        <span>ALERTS [{active.length}]</span>
+      </div>
+
+      {active.map((alert) => {
+        const style = SEVERITY_STYLES[alert.severity];
+        const Icon = style.icon;
+        return (
+          <div
+            key={alert.alert_id}
+            className={`flex items-start justify-between gap-3 bg-slate-900/70 border-l-4 ${style.border} rounded px-3 py-2 font-mono text-xs`}
+          >
+            <div className="flex items-start gap-2 min-w-0">
+              <Icon className={`w-4 h-4 flex-shrink-0 mt-0.5 ${style.text}`} />
+              <div className="min-w-0">
+                <div className={`${style.text} ${alert.state === "raised" ? "font-bold" : ""}`}>
+                  {alert.message}
+                </div>
+                <div className="text-slate-500">
+                  {alert.entity_id ? `${alert.entity_id} · ` : ""}
+                  {alert.source} · {new Date(alert.raised_at).toLocaleTimeString()}
+                  {alert.state === "acknowledged" && alert.acknowledged_by && (
+                    <span className="text-syntax-green"> · ack by {alert.acknowledged_by}</span>
+                  )}
+                </div>
+              </div>
+            </div>
+
+            {alert.state === "raised" && (
+              <button
+                onClick={() => onAcknowledge(alert.alert_id)}
+                disabled={disabled}
+                className="btn-success px-2 py-1 rounded flex items-center gap-1 flex-shrink-0 disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+              >
+                <Check className="w-3 h-3" />
+                ack()
+              </button>
+            )}
+          </div>
+        );
+      })}
+    </div>
+  );
+};
@@ -0,0 +1,56 @@
+import React from "react";
+import type { BehaviorNodeStatus, BehaviorTreeNode, BehaviorTreeStatus } from "@robo-fleet/shared/types";
+
+export interface BehaviorTreeViewProps {
+  status: BehaviorTreeStatus | null;
+}
+
+const STATUS_COLORS: Record<BehaviorNodeStatus, string> = {
+  idle: "text-slate-500",
+  running: "text-syntax-yellow",
+  success: "text-syntax-green",
+  failure: "text-syntax-red",
+};
+
+const KIND_GLYPHS: Record<BehaviorTreeNode["kind"], string> = {
+  sequence: "→",
+  fallback: "?",
+  parallel: "⇉",
+  decorator: "◇",
+  condition: "○",
+  action: "▸",
+};
+
+const TreeNode: React.FC<{ node: BehaviorTreeNode; depth: number }> = ({ node, depth }) => (
+  <>
+    <div className="flex items-center gap-2" style={{ paddingLeft: `${depth * 12}px` }}>
+      <span className="text-slate-600 w-3 text-center">{KIND_GLYPHS[node.kind]}</span>
+      <span className={`${STATUS_COLORS[node.status]} ${node.status === "running" ? "font-bold" : ""}`}>
+        {node.name}
+      </span>
+      <span className="text-slate-600">[{node.status}]</span>
+    </div>
+    {node.children.map((child) => (
+      <TreeNode key={child.id} node={child} depth={depth + 1} />
+    ))}
+  </>
+);
+
+export const BehaviorTreeView: React.FC<BehaviorTreeViewProps> = ({ status }) => {
+  if (!status) {
+    return (
+      <div className="text-slate-600 text-center py-8">
+        // no behavior tree running
+      </div>
+    );
+  }
+
+  return (
+    <div className="space-y-0.5">
+      <div className="text-syntax-purple mb-2">
+        {status.tree} <span className="text-slate-600">tick #{status.tick}</span>
+      </div>
+      <TreeNode node={status.root} depth={0} />
+    </div>
+  );
+};
@@ -0,0 +1,171 @@
+import React, { useCallback, useEffect, useRef, useState } from "react";
+import { Mic, PhoneOff } from "lucide-react";
+import type {
+  IntercomSession,
+  IntercomStartRequest,
+  IntercomStats,
+} from "@robo-fleet/shared/types";
+
+// ~43ms per chunk at 48kHz — small enough for conversation, large enough to avoid socket spam
+const CAPTURE_BUFFER_SIZE = 2048;
+
+export interface IntercomPanelProps {
+  session: IntercomSession | null;
+  stats: IntercomStats | null;
+  onStart: (request: IntercomStartRequest) => void;
+  onStop: (sessionId: string) => void;
+  onAudio: (audioData: number[], sessionId: string) => void;
+  disabled?: boolean;
+}
+
+// Convert Float32 samples [-1.0, 1.0] to S16LE bytes
+const floatToS16leBytes = (samples: Float32Array): number[] => {
+  const bytes = new Array<number>(samples.length * 2);
+  for (let i = 0; i < samples.length; i++) {
+    const clamped = Math.max(-1, Math.min(1, samples[i]));
+    const value = clamped < 0 ? clamped * 0x8000 : clamped * 0x7fff;
+    const sample = Math.round(value) & 0xffff;
+    bytes[i * 2] = sample & 0xff;
+    bytes[i * 2 + 1] = (sample >> 8) & 0xff;
+  }
+  return bytes;
+};
+
+export const IntercomPanel: React.FC<IntercomPanelProps> = ({
+  session,
+  stats,
+  onStart,
+  onStop,
+  onAudio,
+  disabled = false,
+}) => {
+  const [micError, setMicError] = useState<string | null>(null);
+  const [opening, setOpening] = useState(false);
+
+  const mediaStreamRef = useRef<MediaStream | null>(null);
+  const captureContextRef = useRef<AudioContext | null>(null);
+  const processorRef = useRef<ScriptProcessorNode | null>(null);
+  const sessionIdRef = useRef<string | null>(null);
+  const onAudioRef = useRef(onAudio);
+  onAudioRef.current = onAudio;
+  const onStopRef = useRef(onStop);
+  onStopRef.current = onStop;
+  const unmountedRef = useRef(false);
+
+  const isActive = session?.state === "active";
+  sessionIdRef.current = isActive ? session.session_id : null;
+
+  const closeMic = useCallback(() => {
+    processorRef.current?.disconnect();
+    processorRef.current = null;
+    mediaStreamRef.current?.getTracks().forEach((track) => track.stop());
+    mediaStreamRef.current = null;
+    captureContextRef.current?.close();
+    captureContextRef.current = null;
+  }, []);
+
+  const startSession = async () => {
+    setMicError(null);
+    setOpening(true);
+    try {
+      const stream = await navigator.mediaDevices.getUserMedia({
+        audio: { echoCancellation: true, noiseSuppression: true, channelCount: 1 },
+      });
+      mediaStreamRef.current = stream;
+
+      // Unmounted while the permission prompt was open — nobody owns the mic anymore
+      if (unmountedRef.current) {
+        closeMic();
+        return;
+      }
+
+      const context = new AudioContext();
+      const source = context.createMediaStreamSource(stream);
+      const processor = context.createScriptProcessor(CAPTURE_BUFFER_SIZE, 1, 1);
+
+      // Only uplink once the server has confirmed the session
+      processor.onaudioprocess = (event) => {
+        const sessionId = sessionIdRef.current;
+        if (!sessionId) return;
+        onAudioRef.current(floatToS16leBytes(event.inputBuffer.getChannelData(0)), sessionId);
+      };
+      source.connect(processor);
+      processor.connect(context.destination);
+
+      captureContextRef.current = context;
+      processorRef.current = processor;
+
+      onStart({ sample_rate: context.sampleRate, channels: 1, format: "s16le" });
+    } catch (error) {
+      closeMic();
+      if (unmountedRef.current) return;
+      setMicError(error instanceof Error ? error.message : String(error));
+    } finally {
+      if (!unmountedRef.current) setOpening(false);
+    }
+  };
+
+  // Release the mic when the server closes or rejects the session
+  useEffect(() => {
+    if (session && session.state !== "active") closeMic();
+  }, [session, closeMic]);
+
+  // Hang up on unmount so the server doesn't hold a session with no uplink until it times out
+  useEffect(() => {
+    unmountedRef.current = false;
+    return () => {
+      unmountedRef.current = true;
+      if (sessionIdRef.current) onStopRef.current(sessionIdRef.current);
+      closeMic();
+    };
+  }, [closeMic]);
+
+  return (
+    <div className="space-y-3 font-mono text-xs">
+      {isActive ? (
+        <button
+          onClick={() => {
+            onStop(session.session_id);
+            closeMic();
+          }}
+          className="btn-destructive w-full px-3 py-2 rounded flex items-center justify-center gap-1.5 font-bold cursor-pointer"
+        >
+          <PhoneOff className="w-3.5 h-3.5" />
+          hang_up()
+        </button>
+      ) : (
+        <button
+          onClick={startSession}
+          disabled={disabled || opening}
+          className="btn-success w-full px-3 py-2 rounded flex items-center justify-center gap-1.5 font-bold disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+        >
+          <Mic className="w-3.5 h-3.5" />
+          {opening ? "opening_mic..." : "start_intercom()"}
+        </button>
+      )}
+
+      {micError && <div className="text-syntax-red">mic_error: {micError}</div>}
+
+      {session && session.state !== "active" && session.reason && (
+        <div className="text-slate-500">
+          // session {session.state}: {session.reason}
+        </div>
+      )}
+
+      {isActive && (
+        <div className="bg-slate-900/70 border border-slate-700 rounded-lg p-3 grid grid-cols-2 gap-x-3 gap-y-1">
+          <span className="text-syntax-orange">session:</span>
+          <span className="text-syntax-cyan truncate">{session.session_id.slice(0, 8)}</span>
+          <span className="text-syntax-orange">latency:</span>
+          <span className="text-syntax-cyan">{stats ? `${stats.latency_ms.toFixed(0)}ms` : "--"}</span>
+          <span className="text-syntax-orange">jitter_buf:</span>
+          <span className="text-syntax-cyan">{stats ? `${stats.jitter_buffer_ms.toFixed(0)}ms` : "--"}</span>
+          <span className="text-syntax-orange">drops:</span>
+          <span className={stats && stats.packets_dropped > 0 ? "text-syntax-yellow" : "text-syntax-cyan"}>
+            {stats ? `${stats.packets_dropped}/${stats.packets_received + stats.packets_dropped}` : "--"}
+          </span>
+        </div>
+      )}
+    </div>
+  );
+};
@@ -0,0 +1,138 @@
+import React, { useState } from "react";
+import { Circle, Play, Square, Trash2 } from "lucide-react";
+import type { MacroCommand, MacroStatus } from "@robo-fleet/shared/types";
+
+export interface MacroPanelProps {
+  status: MacroStatus | null;
+  onCommand: (command: MacroCommand) => void;
+  disabled?: boolean;
+}
+
+export const MacroPanel: React.FC<MacroPanelProps> = ({
+  status,
+  onCommand,
+  disabled = false,
+}) => {
+  const [draftName, setDraftName] = useState("");
+  const [speedScale, setSpeedScale] = useState(1.0);
+
+  const state = status?.state ?? "idle";
+  const macros = status?.macros ?? [];
+
+  const startRecording = () => {
+    const name = draftName.trim();
+    if (!name) return;
+    onCommand({ command: "record_start", name });
+    setDraftName("");
+  };
+
+  return (
+    <div className="space-y-3 font-mono text-xs">
+      {/* Recorder */}
+      <div className="flex gap-2">
+        {state === "recording" ? (
+          <button
+            onClick={() => onCommand({ command: "record_stop" })}
+            disabled={disabled}
+            className="btn-destructive flex-1 px-3 py-2 rounded flex items-center justify-center gap-1.5 font-bold disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+          >
+            <Square className="w-3.5 h-3.5" />
+            stop_recording("{status?.name}")
+          </button>
+        ) : (
+          <>
+            <input
+              type="text"
+              value={draftName}
+              onChange={(e) => setDraftName(e.target.value)}
+              onKeyDown={(e) => { if (e.key === "Enter") startRecording(); }}
+              placeholder="macro_name"
+              disabled={disabled || state !== "idle"}
+              className="glass-input flex-1 px-3 py-2 rounded text-sm font-mono focus:outline-none focus:ring-2 focus:ring-cyan-400/50"
+            />
+            <button
+              onClick={startRecording}
+              disabled={disabled || state !== "idle" || !draftName.trim()}
+              className="btn-warning px-3 py-2 rounded flex items-center gap-1.5 font-bold disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+            >
+              <Circle className="w-3.5 h-3.5 fill-current" />
+              rec
+            </button>
+          </>
+        )}
+      </div>
+
+      {/* Replay speed */}
+      <div className="bg-slate-900/70 border border-slate-700 rounded-lg p-3 space-y-2">
+        <div className="flex justify-between text-slate-300">
+          <span className="text-syntax-orange">speed_scale:</span>
+          <span className="text-syntax-cyan">{speedScale.toFixed(2)}x</span>
+        </div>
+        <input
+          type="range"
+          min="0.25"
+          max="2.0"
+          step="0.25"
+          value={speedScale}
+          onChange={(e) => setSpeedScale(parseFloat(e.target.value))}
+          className="glass-slider w-full"
+        />
+      </div>
+
+      {state === "replaying" && (
+        <div className="flex items-center justify-between gap-2 bg-syntax-green/10 border border-syntax-green/30 rounded px-3 py-2">
+          <span className="text-syntax-green">
+            replaying "{status?.name}" {((status?.progress ?? 0) * 100).toFixed(0)}%
+          </span>
+          <button
+            onClick={() => onCommand({ command: "replay_stop" })}
+            className="text-syntax-red hover:underline cursor-pointer"
+          >
+            stop()
+          </button>
+        </div>
+      )}
+
+      {/* Stored macros */}
+      {macros.length === 0 ? (
+        <div className="text-slate-600 text-center py-4">
+          // no macros recorded
+        </div>
+      ) : (
+        <div className="space-y-1">
+          {macros.map((macro) => (
+            <div
+              key={macro.name}
+              className="flex items-center justify-between gap-2 bg-slate-900/70 border border-slate-700 rounded px-3 py-2"
+            >
+              <div className="min-w-0">
+                <div className="text-syntax-cyan truncate">{macro.name}</div>
+                <div className="text-slate-500">
+                  {macro.waypoint_count} pts · {(macro.duration_ms / 1000).toFixed(1)}s
+                </div>
+              </div>
+              <div className="flex items-center gap-1 flex-shrink-0">
+                <button
+                  onClick={() => onCommand({ command: "replay", name: macro.name, speed_scale: speedScale })}
+                  disabled={disabled || state !== "idle"}
+                  className="p-1.5 rounded text-syntax-green hover:bg-slate-800 disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+                  title="Replay"
+                >
+                  <Play className="w-3.5 h-3.5" />
+                </button>
+                <button
+                  onClick={() => onCommand({ command: "delete", name: macro.name })}
+                  disabled={disabled || state !== "idle"}
+                  className="p-1.5 rounded text-syntax-red hover:bg-slate-800 disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+                  title="Delete"
+                >
+                  <Trash2 className="w-3.5 h-3.5" />
+                </button>
+              </div>
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+};
@@ -0,0 +1,134 @@
+import React, { useEffect, useState } from "react";
+import { RefreshCw, RotateCcw } from "lucide-react";
+import type { ParamDescriptor, ParamSetRequest, ParamValue } from "@robo-fleet/shared/types";
+
+export interface ParameterPanelProps {
+  params: ParamDescriptor[];
+  onSet: (request: Omit<ParamSetRequest, "entity_id">) => void;
+  onRefresh: () => void;
+  disabled?: boolean;
+}
+
+const ParamInput: React.FC<{
+  param: ParamDescriptor;
+  onCommit: (value: ParamValue) => void;
+  disabled: boolean;
+}> = ({ param, onCommit, disabled }) => {
+  const [draft, setDraft] = useState(String(param.value));
+
+  // Pick up values changed elsewhere (other operators, resets)
+  useEffect(() => {
+    setDraft(String(param.value));
+  }, [param.value]);
+
+  if (param.kind === "bool") {
+    return (
+      <button
+        onClick={() => onCommit(!param.value)}
+        disabled={disabled}
+        className={`px-2 py-0.5 rounded border font-bold disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer ${
+          param.value ? "border-green-500 text-syntax-green" : "border-slate-600 text-slate-400"
+        }`}
+      >
+        {param.value ? "true" : "false"}
+      </button>
+    );
+  }
+
+  const commit = () => {
+    if (draft === String(param.value)) return;
+    if (param.kind === "string") {
+      onCommit(draft);
+      return;
+    }
+    const parsed = param.kind === "int" ? parseInt(draft, 10) : parseFloat(draft);
+    if (Number.isNaN(parsed)) {
+      setDraft(String(param.value));
+      return;
+    }
+    onCommit(parsed);
+  };
+
+  return (
+    <input
+      type={param.kind === "string" ? "text" : "number"}
+      value={draft}
+      min={param.min}
+      max={param.max}
+      step={param.kind === "int" ? 1 : "any"}
+      onChange={(e) => setDraft(e.target.value)}
+      onBlur={commit}
+      onKeyDown={(e) => { if (e.key === "Enter") commit(); }}
+      disabled={disabled}
+      className="glass-input w-24 px-2 py-0.5 rounded text-xs font-mono text-right focus:outline-none focus:ring-2 focus:ring-cyan-400/50"
+    />
+  );
+};
+
+export const ParameterPanel: React.FC<ParameterPanelProps> = ({
+  params,
+  onSet,
+  onRefresh,
+  disabled = false,
+}) => {
+  const byNode = params.reduce<Record<string, ParamDescriptor[]>>((groups, param) => {
+    (groups[param.node] ??= []).push(param);
+    return groups;
+  }, {});
+
+  return (
+    <div className="space-y-3 font-mono text-xs">
+      <div className="flex justify-end">
+        <button
+          onClick={onRefresh}
+          disabled={disabled}
+          className="flex items-center gap-1 text-slate-500 hover:text-syntax-cyan disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+        >
+          <RefreshCw className="w-3 h-3" />
+          refresh()
+        </button>
+      </div>
+
+      {params.length === 0 ? (
+        <div className="text-slate-600 text-center py-4">
+          // no parameters registered
+        </div>
+      ) : (
+        Object.entries(byNode).map(([node, nodeParams]) => (
+          <div key={node} className="space-y-1">
+            <div className="text-syntax-purple">{node}</div>
+            {nodeParams.map((param) => (
+              <div
+                key={param.name}
+                className="flex items-center justify-between gap-2 pl-3"
+                title={param.description}
+              >
+                <span className={param.value === param.default ? "text-syntax-cyan" : "text-syntax-yellow"}>
+                  {param.name}
+                  {param.min !== undefined && param.max !== undefined && (
+                    <span className="text-slate-600"> [{param.min}, {param.max}]</span>
+                  )}
+                </span>
+                <div className="flex items-center gap-1 flex-shrink-0">
+                  <ParamInput
+                    param={param}
+                    onCommit={(value) => onSet({ node: param.node, name: param.name, value })}
+                    disabled={disabled}
+                  />
+                  <button
+                    onClick={() => onSet({ node: param.node, name: param.name, value: param.default })}
+                    disabled={disabled || param.value === param.default}
+                    className="p-1 rounded text-slate-500 hover:text-syntax-cyan disabled:opacity-30 disabled:cursor-not-allowed cursor-pointer"
+                    title={`Reset to ${String(param.default)}`}
+                  >
+                    <RotateCcw className="w-3 h-3" />
+                  </button>
+                </div>
+              </div>
+            ))}
+          </div>
+        ))
+      )}
+    </div>
+  );
+};
@@ -0,0 +1,162 @@
+import React, { useEffect, useState } from "react";
+import { RotateCcw, Upload } from "lucide-react";
+import type { PidGains, SearchMode, ServoConfig, ServoConfigUpdate } from "@robo-fleet/shared/types";
+
+export interface ServoTuningPanelProps {
+  /** Config currently applied on the rover, from servo_telemetry */
+  config: ServoConfig | null;
+  onApply: (update: ServoConfigUpdate) => void;
+  disabled?: boolean;
+}
+
+type NumericConfigKey = {
+  [K in keyof ServoConfig]: ServoConfig[K] extends number ? K : never;
+}[keyof ServoConfig];
+
+interface SliderSpec {
+  key: NumericConfigKey;
+  label: string;
+  min: number;
+  max: number;
+  step: number;
+  unit?: string;
+}
+
+const LIMIT_SLIDERS: SliderSpec[] = [
+  { key: "target_distance_m", label: "target_distance", min: 0.3, max: 3.0, step: 0.1, unit: "m" },
+  { key: "standoff_hysteresis_m", label: "hysteresis", min: 0.0, max: 0.5, step: 0.05, unit: "m" },
+  { key: "deadband_x", label: "deadband_x", min: 0.0, max: 0.2, step: 0.01 },
+  { key: "deadband_size", label: "deadband_size", min: 0.0, max: 0.2, step: 0.01 },
+  { key: "max_linear_velocity", label: "max_v", min: 0.05, max: 1.0, step: 0.05, unit: "m/s" },
+  { key: "max_angular_velocity", label: "max_omega", min: 0.1, max: 2.0, step: 0.1, unit: "rad/s" },
+  { key: "search_duration_s", label: "search_duration", min: 0, max: 30, step: 1, unit: "s" },
+];
+
+const SEARCH_MODES: SearchMode[] = ["off", "rotate", "gimbal_sweep"];
+
+const PidRow: React.FC<{
+  label: string;
+  gains: PidGains;
+  onChange: (gains: PidGains) => void;
+  disabled: boolean;
+}> = ({ label, gains, onChange, disabled }) => (
+  <div className="bg-slate-900/70 border border-slate-700 rounded-lg p-3 space-y-2">
+    <div className="text-syntax-purple">{label}</div>
+    <div className="grid grid-cols-3 gap-2">
+      {(["kp", "ki", "kd"] as const).map((term) => (
+        <label key={term} className="flex flex-col gap-1">
+          <span className="text-syntax-orange">{term}:</span>
+          <input
+            type="number"
+            step="0.01"
+            min="0"
+            value={gains[term]}
+            onChange={(e) => onChange({ ...gains, [term]: parseFloat(e.target.value) || 0 })}
+            disabled={disabled}
+            className="glass-input px-2 py-1 rounded text-xs font-mono focus:outline-none focus:ring-2 focus:ring-cyan-400/50"
+          />
+        </label>
+      ))}
+    </div>
+  </div>
+);
+
+export const ServoTuningPanel: React.FC<ServoTuningPanelProps> = ({
+  config,
+  onApply,
+  disabled = false,
+}) => {
+  const [draft, setDraft] = useState<ServoConfig | null>(config);
+  const [dirty, setDirty] = useState(false);
+
+  // Follow the rover's echoed config until the operator starts editing
+  useEffect(() => {
+    if (!dirty) setDraft(config);
+  }, [config, dirty]);
+
+  if (!draft) {
+    return (
+      <div className="text-slate-600 text-center py-4 font-mono text-xs">
+        // waiting for servo_telemetry
+      </div>
+    );
+  }
+
+  const edit = (patch: Partial<ServoConfig>) => {
+    setDraft({ ...draft, ...patch });
+    setDirty(true);
+  };
+
+  return (
+    <div className="space-y-3 font-mono text-xs">
+      <PidRow label="yaw_pid" gains={draft.yaw_pid} onChange={(yaw_pid) => edit({ yaw_pid })} disabled={disabled} />
+      <PidRow label="distance_pid" gains={draft.distance_pid} onChange={(distance_pid) => edit({ distance_pid })} disabled={disabled} />
+
+      <div className="bg-slate-900/70 border border-slate-700 rounded-lg p-3 space-y-2">
+        {LIMIT_SLIDERS.map(({ key, label, min, max, step, unit }) => (
+          <div key={key} className="space-y-1">
+            <div className="flex justify-between text-slate-300">
+              <span className="text-syntax-orange">{label}:</span>
+              <span className="text-syntax-cyan">{draft[key].toFixed(2)}{unit ? ` ${unit}` : ""}</span>
+            </div>
+            <input
+              type="range"
+              min={min}
+              max={max}
+              step={step}
+              value={draft[key]}
+              onChange={(e) => edit({ [key]: parseFloat(e.target.value) })}
+              disabled={disabled}
+              className="glass-slider w-full"
+            />
+          </div>
+        ))}
+      </div>
+
+      <div className="bg-slate-900/70 border border-slate-700 rounded-lg p-3 space-y-2">
+        <div className="text-syntax-orange">on_target_lost:</div>
+        <div className="grid grid-cols-3 gap-1">
+          {SEARCH_MODES.map((mode) => (
+            <button
+              key={mode}
+              onClick={() => edit({ search_mode: mode })}
+              disabled={disabled}
+              className={`px-2 py-1 rounded border font-bold disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer ${
+                draft.search_mode === mode
+                  ? "border-cyan-400 text-syntax-cyan bg-slate-800"
+                  : "border-slate-700 text-slate-400 hover:bg-slate-800"
+              }`}
+            >
+              {mode}
+            </button>
+          ))}
+        </div>
+      </div>
+
+      <div className="flex gap-2">
+        <button
+          onClick={() => {
+            onApply(draft);
+            setDirty(false);
+          }}
+          disabled={disabled || !dirty}
+          className="btn-info flex-1 px-3 py-2 rounded flex items-center justify-center gap-1.5 font-bold disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+        >
+          <Upload className="w-3.5 h-3.5" />
+          apply()
+        </button>
+        <button
+          onClick={() => {
+            setDraft(config);
+            setDirty(false);
+          }}
+          disabled={!dirty}
+          className="btn-secondary px-3 py-2 rounded flex items-center gap-1.5 font-bold disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+          title="Discard edits"
+        >
+          <RotateCcw className="w-3.5 h-3.5" />
+        </button>
+      </div>
+    </div>
+  );
+};
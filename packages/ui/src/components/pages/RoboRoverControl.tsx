@@ -21,23 +21,81 @@ import type { IJoystickUpdateEvent } from "react-joystick-component/build/lib/Jo
 import {
   Activity,
   AlertTriangle,
+  BatteryLow,
+  BatteryMedium,
+  Bug,
   Camera,
+  Compass,
   Eye,
   EyeOff,
   Gauge,
+  GitBranch,
+  Grab,
+  HeartPulse,
   Home,
+  ListVideo,
+  Lock,
+  MapPin,
+  Mic,
+  Move3d,
+  Navigation2,
+  Package,
+  PlugZap,
+  SlidersHorizontal,
+  Users,
+  Wrench,
 } from "lucide-react";
 
 // Import types from shared package
 import type {
+  ArbitrationDecision,
+  ArmJointName,
   AuthErrorEvent,
+  BatteryTelemetry,
+  BehaviorTreeStatus,
+  CartesianPose,
   ConnectionState,
+  ControlOwnership,
+  ControlRequest,
+  DataflowHealth,
+  DockingCommand,
+  DockingStatus,
+  ExploreCommand,
+  ExploreStatus,
+  FleetDiscoveryEvent,
+  FleetInventory,
   FleetStatus,
+  GripperCommand,
+  HomingCommand,
+  HomingStatus,
+  IntercomSession,
+  IntercomStartRequest,
+  IntercomStats,
+  JointLimit,
+  JointLimitsConfig,
   JointPositions,
   LogEntry,
+  MacroCommand,
+  MacroStatus,
+  NodeError,
+  ObstacleGuardConfig,
+  ObstacleGuardStatus,
+  OperatorAlert,
+  ParamDescriptor,
+  ParamList,
+  ParamSetRequest,
+  ParamUpdate,
+  PreferencesUpdate,
+  PresenceUpdate,
+  RateLimitNotice,
+  RoverHealth,
+  SafetyViolation,
+  ServoConfigUpdate,
+  SoundDirection,
   SpeechTranscription,
   SystemMetrics,
   TrackingTelemetry,
+  UserPreferences,
   WebArmCommand,
   WebRoverCommand,
 } from "@robo-fleet/shared/types";
@@ -56,9 +114,30 @@ import { FloatingMetrics } from "../features/FloatingMetrics";
 import { IconBadge } from "../atoms";
 import { CollapsibleSection } from "../molecules";
 import { FleetSelector, JointControlPanel, ServerSettings, type SocketAuth } from "../organisms";
+import { AlertPanel } from "../organisms/AlertPanel";
+import { MacroPanel } from "../organisms/MacroPanel";
+import { IntercomPanel } from "../organisms/IntercomPanel";
+import { ServoTuningPanel } from "../organisms/ServoTuningPanel";
+import { ParameterPanel } from "../organisms/ParameterPanel";
+import { BehaviorTreeView } from "../organisms/BehaviorTreeView";
 import { detectMixedContent } from "../../utils/url-validation";
+import { bodyToWheelVelocities } from "../../utils/kiwi-kinematics";
+
+// Server limits win over the hardcoded JOINT_LIMITS once joint_limits has arrived
+const validateAgainstLimits = (positions: JointPositions, limits: JointLimitsConfig): string | null => {
+  for (const [joint, limit] of Object.entries(limits) as [ArmJointName, JointLimit][]) {
+    const value = positions[joint];
+    if (value < limit.min || value > limit.max) {
+      return `${joint} ${value.toFixed(3)} outside [${limit.min}, ${limit.max}]`;
+    }
+  }
+  return null;
+};
 
 const THROTTLE_DELAY = 100; // ms between updates
+const HEARTBEAT_INTERVAL = 200; // ms between dead-man switch keepalives while moving
+// Well above the ~1s timer clamp browsers apply to background tabs
+const DEADMAN_TIMEOUT = 3000; // ms without a heartbeat before web_bridge stops the rover
 
 // Extended JointPositions with wheel visualization
 interface ExtendedJointPositions extends JointPositions {
@@ -111,9 +190,28 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
   // Telemetry state
   const [servoTelemetry, setServoTelemetry] = useState<TrackingTelemetry | null>(null);
 
+  // Latest command arbitration decision (who is driving)
+  const [arbitration, setArbitration] = useState<ArbitrationDecision | null>(null);
+
+  // Motion control ownership per rover
+  const [controlOwnership, setControlOwnership] = useState<Map<string, ControlOwnership>>(new Map());
+
+  // Runtime-tunable node parameters
+  const [params, setParams] = useState<ParamDescriptor[]>([]);
+
+  // Per-node liveness across the dataflow
+  const [nodeHealth, setNodeHealth] = useState<DataflowHealth | null>(null);
+
+  // Latest recoverable error per pipeline node, keyed "entity/node"
+  const [nodeErrors, setNodeErrors] = useState<Map<string, NodeError>>(new Map());
+
+  // Other operators connected to the bridge
+  const [presence, setPresence] = useState<PresenceUpdate | null>(null);
+
   // Speech recognition state
   const [transcription, setTranscription] = useState<SpeechTranscription | null>(null);
   const [isAudioActive, setIsAudioActive] = useState(false);
+  const [soundDirection, setSoundDirection] = useState<SoundDirection | null>(null);
 
   // Performance metrics state - per robot (entity_id -> metrics)
   const [performanceMetrics, setPerformanceMetrics] = useState<Map<string, SystemMetrics>>(
@@ -122,6 +220,23 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
 
   // Fleet status state
   const [fleetStatus, setFleetStatus] = useState<FleetStatus | null>(null);
+  // Socket handlers are registered once per connection, so read the selection through a ref
+  const selectedEntityRef = useRef<string | null>(null);
+
+  // Behavior tree executor state
+  const [behaviorTree, setBehaviorTree] = useState<BehaviorTreeStatus | null>(null);
+
+  // Software version inventory across orchestra + fleet
+  const [inventory, setInventory] = useState<FleetInventory | null>(null);
+
+  // Operator alerts (alert_id -> latest lifecycle state)
+  const [alerts, setAlerts] = useState<Map<string, OperatorAlert>>(new Map());
+
+  // Battery - per robot (entity_id -> battery)
+  const [batteries, setBatteries] = useState<Map<string, BatteryTelemetry>>(new Map());
+
+  // Link health - per robot (entity_id -> health)
+  const [roverHealth, setRoverHealth] = useState<Map<string, RoverHealth>>(new Map());
 
   const [logs, setLogs] = useState<LogEntry[]>([]);
   const [showCamera, setShowCamera] = useState(false);
@@ -135,6 +250,47 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
     wheel3: 0.0,
   });
 
+  // Cartesian end-effector target (IK solved on the backend) — kept as text so "-" and "0." can be typed
+  const [cartesianDraft, setCartesianDraft] = useState<Record<keyof CartesianPose, string>>({
+    x: "0.2",
+    y: "0.0",
+    z: "0.15",
+    roll: "0.0",
+    pitch: "0.0",
+    yaw: "0.0",
+  });
+
+  // Gripper width/force target
+  const [gripperTarget, setGripperTarget] = useState<GripperCommand>({
+    width: 0.04,
+    max_force: 10,
+  });
+
+  // Motion macro recorder/replayer state
+  const [macroStatus, setMacroStatus] = useState<MacroStatus | null>(null);
+
+  // Full-duplex operator ↔ rover intercom session
+  const [intercomSession, setIntercomSession] = useState<IntercomSession | null>(null);
+  const [intercomStats, setIntercomStats] = useState<IntercomStats | null>(null);
+
+  // Server-provided joint limits (single source of truth in robo_rover_lib)
+  const [jointLimits, setJointLimits] = useState<JointLimitsConfig | null>(null);
+
+  // Collision guard (ultrasonic/ToF obstacle stop)
+  const [obstacleGuard, setObstacleGuard] = useState<ObstacleGuardStatus | null>(null);
+  // Distances being dragged — sent once on release rather than per slider tick
+  const [guardDraft, setGuardDraft] = useState<Pick<ObstacleGuardConfig, "stop_distance_m" | "slow_distance_m"> | null>(null);
+
+  // Return-to-home progress
+  const [homingStatus, setHomingStatus] = useState<HomingStatus | null>(null);
+
+  // Fiducial-guided docking progress
+  const [dockingStatus, setDockingStatus] = useState<DockingStatus | null>(null);
+
+  // Frontier exploration
+  const [exploreStatus, setExploreStatus] = useState<ExploreStatus | null>(null);
+  const [exploreDuration, setExploreDuration] = useState(120);
+
   // Rover velocity controls
   const [roverVelocity, setRoverVelocity] = useState({
     v_x: 0.0,
@@ -145,9 +301,24 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
   // UI state for collapsible sections
   const [expandedSections, setExpandedSections] = useState({
     armJoints: true,
+    cartesian: false,
+    macros: false,
+    intercom: false,
+    servoTuning: false,
+    params: false,
+    operators: false,
+    nodeErrors: false,
+    nodeHealth: false,
     logs: false,
+    inventory: false,
+    behaviorTree: false,
   });
 
+  // Server-side user preferences (loaded on auth)
+  const [preferences, setPreferences] = useState<UserPreferences | null>(null);
+  // Latest preferences including local updates not yet echoed back by the server
+  const preferencesRef = useRef<UserPreferences | null>(null);
+
   const [authError, setAuthError] = useState<string | null>(null);
   const [sessionActive, setSessionActive] = useState(false);
 
@@ -155,6 +326,13 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
   const refreshTimerRef = useRef<ReturnType<typeof setTimeout> | null>(null);
   const lastCommandTime = useRef<number>(0);
   const lastUpdateTime = useRef<number>(Date.now());
+  const defaultRoverAppliedRef = useRef(false);
+  const lastArbitrationWinner = useRef<ArbitrationDecision["winner"]>(null);
+  const batteryStateRef = useRef<Map<string, BatteryTelemetry["state"]>>(new Map());
+  const dockingStateRef = useRef<DockingStatus["state"]>("idle");
+  const controlOwnerRef = useRef<Map<string, string | null>>(new Map());
+  const rateLimitLoggedRef = useRef<Map<string, number>>(new Map());
+  const loggedNodeErrorsRef = useRef<Set<string>>(new Set());
   const MAX_LOGS = 50;
 
   const scheduleRefresh = useCallback((token: string) => {
@@ -269,6 +447,19 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
       }
     });
 
+    socket.on("deadman_triggered", () => {
+      setRoverVelocity({ v_x: 0, v_y: 0, omega_z: 0 });
+      addLog("Dead-man switch: heartbeat lost, rover stopped", "warning");
+    });
+
+    // One log line per event type per retry window — the notices themselves arrive per dropped event
+    socket.on("rate_limited", (data: RateLimitNotice) => {
+      const lastLogged = rateLimitLoggedRef.current.get(data.event) ?? 0;
+      if (data.timestamp - lastLogged < data.retry_after_ms) return;
+      rateLimitLoggedRef.current.set(data.event, data.timestamp);
+      addLog(`Rate limited: ${data.event} (max ${data.limit_per_sec}/s)`, "warning");
+    });
+
     socket.on("command_ack", () => {
       setConnection((prev) => ({
         ...prev,
@@ -276,15 +467,79 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
       }));
     });
 
+    socket.on("safety_violation", (data: SafetyViolation) => {
+      addLog(`SAFETY [${data.kind}] ${data.action}: ${data.message}`, "warning");
+    });
+
+    socket.on("behavior_tree_status", (data: BehaviorTreeStatus) => {
+      setBehaviorTree(data);
+    });
+
+    socket.on("homing_status", (data: HomingStatus) => {
+      setHomingStatus(data);
+    });
+
+    socket.on("explore_status", (data: ExploreStatus) => {
+      setExploreStatus(data);
+    });
+
+    socket.on("docking_status", (data: DockingStatus) => {
+      if (data.state !== dockingStateRef.current) {
+        if (data.state === "docked") addLog("Docked - charging confirmed", "success");
+        if (data.state === "failed") addLog(`Docking failed${data.reason ? `: ${data.reason}` : ""}`, "error");
+        if (data.auto_triggered && data.state === "searching_marker") addLog("Low battery - auto-docking", "warning");
+      }
+      dockingStateRef.current = data.state;
+      setDockingStatus(data);
+    });
+
+    socket.on("obstacle_guard_status", (data: ObstacleGuardStatus) => {
+      setObstacleGuard(data);
+    });
+
+    socket.on("macro_status", (data: MacroStatus) => {
+      setMacroStatus(data);
+    });
+
+    socket.on("intercom_session", (data: IntercomSession) => {
+      setIntercomSession(data);
+      if (data.state === "active") {
+        setIntercomStats(null);
+        addLog(`Intercom session open with ${data.entity_id}`, "success");
+      } else {
+        addLog(`Intercom session ${data.state}${data.reason ? `: ${data.reason}` : ""}`, data.state === "rejected" ? "error" : "info");
+      }
+    });
+
+    socket.on("intercom_stats", (data: IntercomStats) => {
+      setIntercomStats(data);
+    });
+
+    socket.on("joint_limits", (data: JointLimitsConfig) => {
+      setJointLimits(data);
+    });
+
     socket.on("servo_telemetry", (data: TrackingTelemetry) => {
       setServoTelemetry(data);
     });
 
+    socket.on("arbitration_trace", (data: ArbitrationDecision) => {
+      if (data.winner && data.winner !== lastArbitrationWinner.current) {
+        addLog(`Command source: ${data.winner} (${data.reason})`, "info");
+      }
+      lastArbitrationWinner.current = data.winner;
+      setArbitration(data);
+    });
+
     socket.on("transcription", (data: SpeechTranscription) => {
       setTranscription(data);
       addLog(`Transcription: "${data.text}" (${(data.confidence * 100).toFixed(0)}%)`, "info");
     });
 
+    socket.on("sound_direction", (data: SoundDirection) => {
+      setSoundDirection(data);
+    });
+
     socket.on("performance_metrics", (data: SystemMetrics) => {
       if (data.entity_id) {
         setPerformanceMetrics((prev) => {
@@ -292,22 +547,146 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
           newMap.set(data.entity_id!, data);
           return newMap;
         });
-      } else if (fleetStatus?.selected_entity) {
+      } else if (selectedEntityRef.current) {
+        const entityId = selectedEntityRef.current;
         setPerformanceMetrics((prev) => {
           const newMap = new Map(prev);
-          newMap.set(fleetStatus.selected_entity, data);
+          newMap.set(entityId, data);
           return newMap;
         });
       }
     });
 
+    socket.on("preferences", (data: UserPreferences) => {
+      preferencesRef.current = data;
+      setPreferences(data);
+      if (data.layout?.show_camera !== undefined) setShowCamera(data.layout.show_camera);
+      if (data.layout?.show_location_map !== undefined) setShowLocationMap(data.layout.show_location_map);
+    });
+
     socket.on("fleet_status", (data: FleetStatus) => {
+      selectedEntityRef.current = data.selected_entity;
       setFleetStatus(data);
       addLog(`Fleet status: Selected rover is ${data.selected_entity}`, "info");
     });
 
+    // Alerts are re-sent on every lifecycle change and re-announced while unacknowledged
+    socket.on("operator_alert", (data: OperatorAlert) => {
+      setAlerts((prev) => {
+        const newMap = new Map(prev);
+        newMap.set(data.alert_id, data);
+        return newMap;
+      });
+      if (data.state === "raised") {
+        addLog(`ALERT [${data.severity}] ${data.message}`, data.severity === "info" ? "info" : "warning");
+      }
+    });
+
+    socket.on("fleet_inventory", (data: FleetInventory) => {
+      setInventory(data);
+      if (data.mixed_versions) {
+        addLog("Fleet is running mixed software versions", "warning");
+      }
+    });
+
+    socket.on("battery_telemetry", (data: BatteryTelemetry) => {
+      const entityId = data.entity_id ?? selectedEntityRef.current;
+      if (!entityId) return;
+      const previousState = batteryStateRef.current.get(entityId);
+      if (previousState !== data.state && (data.state === "low" || data.state === "critical")) {
+        addLog(`Battery ${data.state} on ${entityId}: ${data.percentage.toFixed(0)}%`, "warning");
+      }
+      batteryStateRef.current.set(entityId, data.state);
+      setBatteries((prev) => {
+        const newMap = new Map(prev);
+        newMap.set(entityId, data);
+        return newMap;
+      });
+    });
+
+    socket.on("control_ownership", (data: ControlOwnership) => {
+      const previous = controlOwnerRef.current.get(data.entity_id);
+      const current = data.owner?.client_id ?? data.owner?.source ?? null;
+      if (previous !== undefined && previous !== current) {
+        if (data.owner?.client_id === socket.id) {
+          addLog(`You have control of ${data.entity_id}`, "success");
+        } else if (previous === socket.id) {
+          addLog(`Lost control of ${data.entity_id}${data.owner ? ` to ${data.owner.username ?? data.owner.source}` : ""}`, "warning");
+        }
+      }
+      controlOwnerRef.current.set(data.entity_id, current);
+      setControlOwnership((prev) => {
+        const newMap = new Map(prev);
+        newMap.set(data.entity_id, data);
+        return newMap;
+      });
+    });
+
+    socket.on("param_list", (data: ParamList) => {
+      setParams(data.params);
+    });
+
+    socket.on("param_update", (data: ParamUpdate) => {
+      if (!data.accepted) {
+        addLog(`Parameter ${data.node}.${data.name} rejected: ${data.error ?? "unknown error"}`, "error");
+        return;
+      }
+      setParams((prev) =>
+        prev.map((p) => (p.node === data.node && p.name === data.name ? { ...p, value: data.value } : p)),
+      );
+    });
+
+    socket.on("node_health", (data: DataflowHealth) => {
+      setNodeHealth(data);
+    });
+
+    socket.on("node_error", (data: NodeError) => {
+      const key = `${data.entity_id ?? "orchestra"}/${data.node}`;
+      // Coalesced re-sends only update NODE_ERRORS — don't let one noisy node flood the log
+      if (data.count === 1 || !loggedNodeErrorsRef.current.has(key)) {
+        addLog(`[${key}] ${data.kind}: ${data.message}`, "error");
+        loggedNodeErrorsRef.current.add(key);
+      }
+      setNodeErrors((prev) => {
+        const newMap = new Map(prev);
+        newMap.set(key, data);
+        return newMap;
+      });
+    });
+
+    socket.on("presence", (data: PresenceUpdate) => {
+      setPresence(data);
+    });
+
+    socket.on("rover_health", (data: RoverHealth) => {
+      setRoverHealth((prev) => {
+        const newMap = new Map(prev);
+        newMap.set(data.entity_id, data);
+        return newMap;
+      });
+    });
+
+    // Liveliness-based discovery — keep the roster in sync between fleet_status updates
+    socket.on("fleet_discovery", (data: FleetDiscoveryEvent) => {
+      setFleetStatus((prev) => {
+        if (!prev) return prev;
+        const inRoster = prev.fleet_roster.includes(data.entity_id);
+        if (data.presence === "online" && !inRoster) {
+          return { ...prev, fleet_roster: [...prev.fleet_roster, data.entity_id], timestamp: data.timestamp };
+        }
+        if (data.presence === "offline" && inRoster) {
+          return { ...prev, fleet_roster: prev.fleet_roster.filter((id) => id !== data.entity_id), timestamp: data.timestamp };
+        }
+        return prev;
+      });
+      addLog(
+        `Rover ${data.entity_id} is ${data.presence}`,
+        data.presence === "online" ? "success" : "warning",
+      );
+    });
+
     socketRef.current = socket;
-  }, [serverUrl, socketAuth, addLog, fleetStatus?.selected_entity, scheduleRefresh]);
+  }, [serverUrl, socketAuth, addLog, scheduleRefresh]);
 
   // Disconnect from Socket.IO server
   const disconnect = useCallback(() => {
@@ -321,8 +700,31 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
       refreshTimerRef.current = null;
     }
     setSessionActive(false);
+    defaultRoverAppliedRef.current = false;
   }, [addLog]);
 
+  // Persist a partial preferences update server-side and apply it locally
+  const updatePreferences = useCallback((update: PreferencesUpdate) => {
+    if (!socketRef.current?.connected) return;
+    const next: UserPreferences = { ...preferencesRef.current, ...update, updated_at: Date.now() };
+    preferencesRef.current = next;
+    setPreferences(next);
+    socketRef.current.emit("preferences_update", update);
+  }, []);
+
+  // Acknowledge an operator alert
+  const acknowledgeAlert = useCallback(
+    (alertId: string) => {
+      if (!connection.isConnected || !socketRef.current) {
+        addLog("Cannot acknowledge alert - not connected", "error");
+        return;
+      }
+
+      socketRef.current.emit("alert_ack", { alert_id: alertId, timestamp: Date.now() });
+    },
+    [connection.isConnected, addLog],
+  );
+
   // Select rover from fleet
   const selectRover = useCallback(
     (entityId: string) => {
@@ -333,9 +735,10 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
 
       const selectCommand = createFleetSelectCommand(entityId);
       socketRef.current.emit("fleet_select", selectCommand);
+      updatePreferences({ default_rover: entityId });
       addLog(`Switching to rover: ${entityId}`, "info");
     },
-    [connection.isConnected, addLog],
+    [connection.isConnected, addLog, updatePreferences],
   );
 
   // Send ARM command
@@ -395,17 +798,44 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
     addLog("Audio capture stopped", "info");
   }, [connection.isConnected, addLog]);
 
+  // Switch to the user's preferred rover once per session, if it is in the roster
+  useEffect(() => {
+    const defaultRover = preferences?.default_rover;
+    if (!defaultRover || !fleetStatus || defaultRoverAppliedRef.current) return;
+
+    defaultRoverAppliedRef.current = true;
+    if (fleetStatus.selected_entity !== defaultRover && fleetStatus.fleet_roster.includes(defaultRover)) {
+      selectRover(defaultRover);
+    }
+  }, [preferences?.default_rover, fleetStatus, selectRover]);
+
+  // Panel visibility — also saved as a layout hint
+  const setCameraVisible = useCallback((visible: boolean) => {
+    setShowCamera(visible);
+    updatePreferences({ layout: { ...preferencesRef.current?.layout, show_camera: visible } });
+  }, [updatePreferences]);
+
+  const setLocationMapVisible = useCallback((visible: boolean) => {
+    setShowLocationMap(visible);
+    updatePreferences({ layout: { ...preferencesRef.current?.layout, show_location_map: visible } });
+  }, [updatePreferences]);
+
   // Update joint position
   const updateJoint = useCallback((joint: keyof JointPositions, value: number) => {
+    const limit = jointLimits?.[joint as keyof JointLimitsConfig];
+    const clamped = limit ? Math.min(limit.max, Math.max(limit.min, value)) : value;
+
     setJointPositions((prev) => {
-      const newPositions = { ...prev, [joint]: value };
-      const error = validateJointPositions(newPositions);
+      const newPositions = { ...prev, [joint]: clamped };
+      const error = jointLimits
+        ? validateAgainstLimits(newPositions, jointLimits)
+        : validateJointPositions(newPositions);
       if (error) {
         console.warn(error);
       }
       return newPositions as ExtendedJointPositions;
     });
-  }, []);
+  }, [jointLimits]);
 
   // Real-time ARM joint control
   useEffect(() => {
@@ -461,6 +891,33 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
     sendThrottled,
   ]);
 
+  const isMoving = roverVelocity.v_x !== 0 || roverVelocity.v_y !== 0 || roverVelocity.omega_z !== 0;
+
+  // Dead-man switch — only beat while motion is commanded, so a lapse means the operator is gone
+  useEffect(() => {
+    if (!connection.isConnected || !isMoving) return;
+
+    let seq = 0;
+    const beat = () => {
+      socketRef.current?.emit("heartbeat", { seq: seq++, timestamp: Date.now(), timeout_ms: DEADMAN_TIMEOUT });
+    };
+    beat();
+    const intervalId = setInterval(beat, HEARTBEAT_INTERVAL);
+
+    return () => clearInterval(intervalId);
+  }, [connection.isConnected, isMoving]);
+
+  // Backgrounded tabs can't hold a control, so stop instead of coasting until the timeout
+  useEffect(() => {
+    const handleVisibilityChange = () => {
+      if (document.hidden) {
+        setRoverVelocity({ v_x: 0, v_y: 0, omega_z: 0 });
+      }
+    };
+    document.addEventListener("visibilitychange", handleVisibilityChange);
+    return () => document.removeEventListener("visibilitychange", handleVisibilityChange);
+  }, []);
+
   // Integrate wheel velocities into wheel positions for visualization
   useEffect(() => {
     const intervalId = setInterval(() => {
@@ -468,13 +925,7 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
       const dt = (now - lastUpdateTime.current) / 1000;
       lastUpdateTime.current = now;
 
-      const WHEEL_RADIUS = 0.05;
-      const ROBOT_RADIUS = 0.15;
-      const { v_x, v_y, omega_z } = roverVelocity;
-
-      const omega1 = (v_y / WHEEL_RADIUS) + (omega_z * ROBOT_RADIUS / WHEEL_RADIUS);
-      const omega2 = ((-0.5 * v_y + 0.866 * v_x) / WHEEL_RADIUS) + (omega_z * ROBOT_RADIUS / WHEEL_RADIUS);
-      const omega3 = ((-0.5 * v_y - 0.866 * v_x) / WHEEL_RADIUS) + (omega_z * ROBOT_RADIUS / WHEEL_RADIUS);
+      const [omega1, omega2, omega3] = bodyToWheelVelocities(roverVelocity);
 
       setJointPositions((prev) => ({
         ...prev,
@@ -522,7 +973,198 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
     });
   }, [sendArmCommand]);
 
-  // Emergency stop
+  // Gripper width/force move
+  const sendGripper = useCallback(() => {
+    sendArmCommand({ command_type: "gripper", gripper: gripperTarget });
+  }, [sendArmCommand, gripperTarget]);
+
+  // Return-to-home
+  const sendHomingCommand = useCallback(
+    (command: HomingCommand) => {
+      if (!connection.isConnected || !socketRef.current) {
+        addLog("Cannot send homing command - not connected", "error");
+        return;
+      }
+
+      socketRef.current.emit("homing_command", command);
+      const messages: Record<HomingCommand["command"], string> = {
+        return_home: "Returning to home pose",
+        set_home: "Home pose set to current position",
+        cancel: "Return-to-home cancelled",
+      };
+      addLog(messages[command.command], "info");
+    },
+    [connection.isConnected, addLog],
+  );
+
+  // Frontier exploration
+  const sendExploreCommand = useCallback(
+    (command: ExploreCommand) => {
+      if (!connection.isConnected || !socketRef.current) {
+        addLog("Cannot send explore command - not connected", "error");
+        return;
+      }
+
+      socketRef.current.emit("explore_command", command);
+      addLog(command.command === "start" ? `Exploring for up to ${command.max_duration_s}s` : "Exploration stopped", "info");
+    },
+    [connection.isConnected, addLog],
+  );
+
+  // Docking
+  const sendDockingCommand = useCallback(
+    (command: DockingCommand) => {
+      if (!connection.isConnected || !socketRef.current) {
+        addLog("Cannot send docking command - not connected", "error");
+        return;
+      }
+
+      socketRef.current.emit("docking_command", command);
+      const messages: Record<DockingCommand["command"], string> = {
+        dock: "Docking started",
+        undock: "Undocking",
+        cancel: "Docking cancelled",
+      };
+      addLog(messages[command.command], "info");
+    },
+    [connection.isConnected, addLog],
+  );
+
+  // Control ownership take/release/steal
+  const requestControl = useCallback(
+    (action: ControlRequest["action"]) => {
+      if (!connection.isConnected || !socketRef.current) {
+        addLog("Cannot request control - not connected", "error");
+        return;
+      }
+
+      socketRef.current.emit("control_request", { action, entity_id: fleetStatus?.selected_entity });
+    },
+    [connection.isConnected, fleetStatus?.selected_entity, addLog],
+  );
+
+  // Obstacle guard configuration
+  const updateObstacleGuard = useCallback(
+    (config: Partial<ObstacleGuardConfig>) => {
+      if (!connection.isConnected || !socketRef.current) {
+        addLog("Cannot update obstacle guard - not connected", "error");
+        return;
+      }
+
+      socketRef.current.emit("obstacle_guard", config);
+      if (config.enabled !== undefined) {
+        addLog(`Obstacle stop ${config.enabled ? "enabled" : "disabled"}`, config.enabled ? "info" : "warning");
+      }
+    },
+    [connection.isConnected, addLog],
+  );
+
+  const commitGuardDraft = useCallback(() => {
+    if (!guardDraft) return;
+    updateObstacleGuard(guardDraft);
+    // Show the committed values until the next obstacle_guard_status echo
+    setObstacleGuard((prev) => (prev ? { ...prev, ...guardDraft } : prev));
+    setGuardDraft(null);
+  }, [guardDraft, updateObstacleGuard]);
+
+  // Motion macro record/replay
+  const sendMacroCommand = useCallback(
+    (command: MacroCommand) => {
+      if (!connection.isConnected || !socketRef.current) {
+        addLog("Cannot send macro command - not connected", "error");
+        return;
+      }
+
+      socketRef.current.emit("macro_command", command);
+      if (command.command === "record_start") addLog(`Recording macro: ${command.name}`, "info");
+      if (command.command === "replay") addLog(`Replaying macro: ${command.name} @ ${command.speed_scale}x`, "info");
+    },
+    [connection.isConnected, addLog],
+  );
+
+  // Live visual-servo tuning
+  const sendServoConfig = useCallback(
+    (update: ServoConfigUpdate) => {
+      if (!connection.isConnected || !socketRef.current) {
+        addLog("Cannot update servo config - not connected", "error");
+        return;
+      }
+
+      socketRef.current.emit("servo_config", update);
+      addLog("Servo config applied", "info");
+    },
+    [connection.isConnected, addLog],
+  );
+
+  // Runtime parameter service
+  const requestParams = useCallback(() => {
+    if (!connection.isConnected || !socketRef.current) {
+      addLog("Cannot list parameters - not connected", "error");
+      return;
+    }
+
+    socketRef.current.emit("param_get", { entity_id: fleetStatus?.selected_entity });
+  }, [connection.isConnected, fleetStatus?.selected_entity, addLog]);
+
+  const setParam = useCallback(
+    (request: Omit<ParamSetRequest, "entity_id">) => {
+      if (!connection.isConnected || !socketRef.current) {
+        addLog("Cannot set parameter - not connected", "error");
+        return;
+      }
+
+      socketRef.current.emit("param_set", { ...request, entity_id: fleetStatus?.selected_entity });
+      addLog(`Set ${request.node}.${request.name} = ${String(request.value)}`, "info");
+    },
+    [connection.isConnected, fleetStatus?.selected_entity, addLog],
+  );
+
+  // Intercom session handshake and uplink audio
+  const startIntercom = useCallback(
+    (request: IntercomStartRequest) => {
+      if (!connection.isConnected || !socketRef.current) {
+        addLog("Cannot start intercom - not connected", "error");
+        return;
+      }
+
+      socketRef.current.emit("intercom_start", { ...request, entity_id: fleetStatus?.selected_entity });
+    },
+    [connection.isConnected, fleetStatus?.selected_entity, addLog],
+  );
+
+  const stopIntercom = useCallback(
+    (sessionId: string) => {
+      socketRef.current?.emit("intercom_stop", { session_id: sessionId });
+    },
+    [],
+  );
+
+  const sendIntercomAudio = useCallback(
+    (audioData: number[], sessionId: string) => {
+      if (!socketRef.current?.connected) return;
+      socketRef.current.emit("audio_stream", { audio_data: audioData, session_id: sessionId });
+    },
+    [],
+  );
+
+  // Cartesian move
+  const sendCartesianMove = useCallback(() => {
+    const entries = Object.entries(cartesianDraft).map(([axis, text]) => [axis, parseFloat(text)] as const);
+    const invalid = entries.find(([, value]) => Number.isNaN(value));
+    if (invalid) {
+      addLog(`Cartesian move: invalid ${invalid[0]} "${cartesianDraft[invalid[0] as keyof CartesianPose]}"`, "error");
+      return;
+    }
+
+    const pose = Object.fromEntries(entries) as unknown as CartesianPose;
+    sendArmCommand({ command_type: "cartesian", pose });
+    addLog(
+      `Cartesian move → (${pose.x.toFixed(3)}, ${pose.y.toFixed(3)}, ${pose.z.toFixed(3)})`,
+      "info",
+    );
+  }, [sendArmCommand, cartesianDraft, addLog]);
+
+  // Emergency stop — selected rover only
   const emergencyStop = useCallback(() => {
     sendArmCommand({ command_type: "stop" });
     sendRoverCommand({ command_type: "stop" });
@@ -530,6 +1172,14 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
     addLog("EMERGENCY STOP ACTIVATED", "warning");
   }, [sendArmCommand, sendRoverCommand, addLog]);
 
+  // Fleet-wide stop — fanned out to every active rover, not just the selected one
+  const emergencyStopAll = useCallback(() => {
+    sendArmCommand({ command_type: "stop", target: { mode: "all_active" } });
+    sendRoverCommand({ command_type: "stop", target: { mode: "all_active" } });
+    setRoverVelocity({ v_x: 0, v_y: 0, omega_z: 0 });
+    addLog("FLEET-WIDE STOP ACTIVATED - all active rovers", "warning");
+  }, [sendArmCommand, sendRoverCommand, addLog]);
+
   // Handle connect from settings dialog — saves url + auth to localStorage, reconnects
   const handleConnectSettings = useCallback((url: string, auth: SocketAuth | undefined) => {
     localStorage.setItem(STORAGE_KEY, url);
@@ -559,6 +1209,12 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
     };
   }, []);
 
+  const selectedHealth = fleetStatus ? roverHealth.get(fleetStatus.selected_entity) : undefined;
+  const selectedBattery = fleetStatus ? batteries.get(fleetStatus.selected_entity) : undefined;
+  const selectedOwner = fleetStatus ? controlOwnership.get(fleetStatus.selected_entity)?.owner : undefined;
+  const unhealthyNodes = nodeHealth?.nodes.filter((n) => n.state !== "alive").length ?? 0;
+  const ownsControl = !!selectedOwner && selectedOwner.client_id === socketRef.current?.id;
+
   return (
     <div className="min-h-screen gradient-bg relative scanline-effect">
       <div className="relative z-10 max-w-7xl mx-auto">
@@ -616,6 +1272,132 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
                       {servoTelemetry.distance_estimate?.toFixed(1)}m
                     </span>
                   )}
+                  {servoTelemetry.approach_state && (
+                    <span
+                      className={`text-xs font-mono ml-1 ${
+                        servoTelemetry.approach_state === "Holding"
+                          ? "text-syntax-green"
+                          : servoTelemetry.approach_state === "Backing"
+                            ? "text-syntax-orange"
+                            : "text-syntax-yellow"
+                      }`}
+                    >
+                      {servoTelemetry.approach_state.toLowerCase()}
+                    </span>
+                  )}
+                  {servoTelemetry.search && (
+                    <span
+                      className={`text-xs font-mono ml-1 ${
+                        servoTelemetry.search.state === "GaveUp" ? "text-syntax-red" : "text-syntax-yellow animate-pulse"
+                      }`}
+                      title={`${servoTelemetry.search.mode} ${servoTelemetry.search.direction}`}
+                    >
+                      {servoTelemetry.search.state === "GaveUp"
+                        ? "lost"
+                        : `searching ${servoTelemetry.search.elapsed_s.toFixed(0)}s`}
+                    </span>
+                  )}
+                </div>
+              )}
+
+              {/* Control Ownership - selected rover */}
+              {connection.isConnected && fleetStatus && (
+                <button
+                  onClick={() => requestControl(ownsControl ? "release" : selectedOwner ? "steal" : "take")}
+                  className="bg-slate-900/80 border border-slate-700 rounded px-2 py-1 flex items-center gap-1.5 hover:bg-slate-800 cursor-pointer"
+                  title={
+                    ownsControl
+                      ? "Release control"
+                      : selectedOwner
+                        ? `Held by ${selectedOwner.username ?? selectedOwner.source} since ${new Date(selectedOwner.since).toLocaleTimeString()} — click to steal`
+                        : "Take control"
+                  }
+                >
+                  <Lock className={`w-3 h-3 ${ownsControl ? "text-syntax-green" : selectedOwner ? "text-syntax-red" : "text-slate-500"}`} />
+                  <span className="text-xs font-mono text-slate-500">ctl:</span>
+                  <span
+                    className={`text-xs font-mono font-semibold ${
+                      ownsControl ? "text-syntax-green" : selectedOwner ? "text-syntax-red" : "text-slate-400"
+                    }`}
+                  >
+                    {ownsControl ? "you" : selectedOwner ? (selectedOwner.username ?? selectedOwner.source) : "free"}
+                  </span>
+                </button>
+              )}
+
+              {/* Command Source - arbitration winner */}
+              {arbitration?.winner && (
+                <div
+                  className="bg-slate-900/80 border border-slate-700 rounded px-2 py-1 flex items-center gap-1.5"
+                  title={`${arbitration.reason} — ${arbitration.candidates.map((c) => c.source).join(", ")}`}
+                >
+                  <span className="text-xs font-mono text-slate-500">src:</span>
+                  <span className="text-xs font-mono font-semibold text-syntax-orange">
+                    {arbitration.winner}
+                  </span>
+                </div>
+              )}
+
+              {/* Sound Direction - bearing of dominant voice source */}
+              {soundDirection?.is_speech && soundDirection.confidence > 0.5 && (
+                <div
+                  className="bg-slate-900/80 border border-slate-700 rounded px-2 py-1 flex items-center gap-1.5"
+                  title="Direction of dominant sound source"
+                >
+                  <Navigation2
+                    className="w-3 h-3 text-syntax-yellow"
+                    style={{ transform: `rotate(${-soundDirection.bearing}rad)` }}
+                  />
+                  <span className="text-xs font-mono text-syntax-yellow">
+                    {((soundDirection.bearing * 180) / Math.PI).toFixed(0)}°
+                  </span>
+                </div>
+              )}
+
+              {/* Battery - selected rover */}
+              {selectedBattery && (
+                <div
+                  className="bg-slate-900/80 border border-slate-700 rounded px-2 py-1 flex items-center gap-1.5"
+                  title={`${selectedBattery.voltage.toFixed(2)}V${
+                    selectedBattery.max_speed_limit !== null ? ` — speed limited to ${selectedBattery.max_speed_limit} m/s` : ""
+                  }`}
+                >
+                  {selectedBattery.state === "low" || selectedBattery.state === "critical" ? (
+                    <BatteryLow className={`w-4 h-4 ${selectedBattery.state === "critical" ? "text-syntax-red animate-pulse" : "text-syntax-yellow"}`} />
+                  ) : (
+                    <BatteryMedium className="w-4 h-4 text-syntax-green" />
+                  )}
+                  <span
+                    className={`text-xs font-mono font-semibold ${
+                      selectedBattery.state === "critical"
+                        ? "text-syntax-red"
+                        : selectedBattery.state === "low"
+                          ? "text-syntax-yellow"
+                          : "text-syntax-green"
+                    }`}
+                  >
+                    {selectedBattery.percentage.toFixed(0)}%
+                  </span>
+                </div>
+              )}
+
+              {/* Link Health - selected rover */}
+              {selectedHealth && (
+                <div
+                  className="bg-slate-900/80 border border-slate-700 rounded px-2 py-1 flex items-center gap-1.5"
+                  title={`Last seen ${new Date(selectedHealth.last_seen).toLocaleTimeString()}`}
+                >
+                  <div
+                    className={`w-2 h-2 rounded-full ${
+                      selectedHealth.is_alive ? "bg-syntax-green status-glow-green" : "bg-syntax-red status-glow-red"
+                    }`}
+                  ></div>
+                  <span className="text-xs font-mono text-slate-400">
+                    link:{" "}
+                    <span className={selectedHealth.is_alive ? "text-syntax-green" : "text-syntax-red"}>
+                      {selectedHealth.rtt_ms !== undefined ? `${selectedHealth.rtt_ms.toFixed(0)}ms` : selectedHealth.is_alive ? "up" : "down"}
+                    </span>
+                  </span>
                 </div>
               )}
 
@@ -625,7 +1407,7 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
               </div>
             </div>
 
-            {/* Right: Settings, Emergency Stop */}
+            {/* Right: Settings, Fleet Stop, Emergency Stop */}
             <div className="flex items-center gap-2 w-full md:w-auto">
               <ServerSettings
                 currentUrl={serverUrl}
@@ -637,6 +1419,16 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
                 onDisconnect={disconnect}
               />
 
+              {/* Fleet Stop Button - stops every active rover */}
+              <button
+                onClick={emergencyStopAll}
+                disabled={!connection.isConnected}
+                className="px-3 md:px-4 py-2 bg-slate-900 hover:bg-red-950 text-red-400 rounded font-black text-sm md:text-base border-2 border-red-500/60 transition-all duration-200 disabled:opacity-40 disabled:hover:bg-slate-900 active:scale-95 font-mono cursor-pointer"
+                title="Stop every active rover in the fleet"
+              >
+                <span className="tracking-wider">[ STOP ALL ]</span>
+              </button>
+
               {/* Emergency Stop Button - Terminal style */}
               <button
                 onClick={emergencyStop}
@@ -645,6 +1437,7 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
                 style={{
                   animation: connection.isConnected ? 'pulse-slow 3s infinite' : 'none'
                 }}
+                title="Stop the selected rover"
               >
                 <div className="flex items-center gap-2 justify-center">
                   <AlertTriangle className="w-4 h-4" />
@@ -656,6 +1449,13 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
         </div>
 
         <div className="p-3 md:p-4 space-y-3 md:space-y-4 pt-3 md:pt-4">
+          {/* Operator Alerts */}
+          <AlertPanel
+            alerts={Array.from(alerts.values())}
+            onAcknowledge={acknowledgeAlert}
+            disabled={!connection.isConnected}
+          />
+
           {/* Fleet Selector */}
           <FleetSelector
             fleetStatus={fleetStatus}
@@ -676,7 +1476,7 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
                     </h2>
                   </div>
                   <button
-                    onClick={() => setShowLocationMap(false)}
+                    onClick={() => setLocationMapVisible(false)}
                     className="btn-warning px-3 py-2 rounded text-xs font-mono flex items-center gap-2 cursor-pointer"
                   >
                     <EyeOff className="w-3 h-3" />
@@ -725,7 +1525,8 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
               <CameraViewer
                 isConnected={connection.isConnected}
                 socket={socketRef.current}
-                onClose={() => setShowCamera(false)}
+                serverUrl={serverUrl}
+                onClose={() => setCameraVisible(false)}
               />
             )}
           </div>
@@ -733,7 +1534,7 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
           <div className="grid grid-cols-1 md:grid-cols-2 gap-3">
             {!showLocationMap && (
               <button
-                onClick={() => setShowLocationMap(true)}
+                onClick={() => setLocationMapVisible(true)}
                 className="w-full py-3 bg-slate-900/70 border border-slate-700 rounded-lg text-slate-300 hover:text-syntax-purple hover:border-syntax-purple/50 transition-all font-mono text-sm flex items-center justify-center gap-2 cursor-pointer"
               >
                 <Eye className="w-4 h-4" />
@@ -745,7 +1546,7 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
             )}
             {!showCamera && (
               <button
-                onClick={() => setShowCamera(true)}
+                onClick={() => setCameraVisible(true)}
                 className="w-full py-3 bg-slate-900/70 border border-slate-700 rounded-lg text-slate-300 hover:text-syntax-cyan hover:border-syntax-cyan/50 transition-all font-mono text-sm flex items-center justify-center gap-2 cursor-pointer"
               >
                 <Camera className="w-4 h-4" />
@@ -775,6 +1576,30 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
             onLog={addLog}
           />
 
+          {/* Intercom */}
+          <CollapsibleSection
+            title={`INTERCOM${intercomSession?.state === "active" ? " [LIVE]" : ""}`}
+            isExpanded={expandedSections.intercom}
+            onToggle={() =>
+              setExpandedSections((prev) => ({
+                ...prev,
+                intercom: !prev.intercom,
+              }))
+            }
+            headerRight={
+              <IconBadge icon={Mic} color="text-syntax-green" size="md" />
+            }
+          >
+            <IntercomPanel
+              session={intercomSession}
+              stats={intercomStats}
+              onStart={startIntercom}
+              onStop={stopIntercom}
+              onAudio={sendIntercomAudio}
+              disabled={!connection.isConnected}
+            />
+          </CollapsibleSection>
+
           {/* Main Control Grid */}
           <div className="grid grid-cols-1 lg:grid-cols-2 gap-3 md:gap-4">
             {/* LEFT COLUMN: ROVER CONTROL */}
@@ -834,6 +1659,222 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
                     <span>+1.0</span>
                   </div>
                 </div>
+
+                {/* Return to Home */}
+                <div className="mt-4 bg-slate-900/70 border border-slate-700 rounded-lg p-4 md:p-5 space-y-3 font-mono text-xs">
+                  <div className="grid grid-cols-2 gap-2">
+                    {homingStatus?.state === "navigating" || homingStatus?.state === "blocked" ? (
+                      <button
+                        onClick={() => sendHomingCommand({ command: "cancel" })}
+                        disabled={!connection.isConnected}
+                        className="btn-destructive py-2 rounded font-bold flex items-center justify-center gap-1.5 disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+                      >
+                        <Navigation2 className="w-3.5 h-3.5" />
+                        cancel()
+                      </button>
+                    ) : (
+                      <button
+                        onClick={() => sendHomingCommand({ command: "return_home" })}
+                        disabled={!connection.isConnected}
+                        className="btn-info py-2 rounded font-bold flex items-center justify-center gap-1.5 disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+                      >
+                        <Navigation2 className="w-3.5 h-3.5" />
+                        return_home()
+                      </button>
+                    )}
+                    <button
+                      onClick={() => sendHomingCommand({ command: "set_home" })}
+                      disabled={!connection.isConnected || homingStatus?.state === "navigating"}
+                      className="btn-secondary py-2 rounded font-bold flex items-center justify-center gap-1.5 disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+                    >
+                      <MapPin className="w-3.5 h-3.5" />
+                      set_home()
+                    </button>
+                  </div>
+                  {homingStatus && homingStatus.state !== "idle" && (
+                    <div className="space-y-1">
+                      <div className="flex justify-between text-slate-300">
+                        <span className={homingStatus.state === "blocked" ? "text-syntax-red" : "text-syntax-orange"}>
+                          homing: {homingStatus.state}
+                        </span>
+                        <span className="text-syntax-cyan">
+                          {homingStatus.distance_remaining_m.toFixed(2)} <span className="text-slate-500">m</span>
+                        </span>
+                      </div>
+                      <div className="h-1.5 bg-slate-800 rounded">
+                        <div
+                          className="h-full bg-syntax-cyan rounded transition-all"
+                          style={{ width: `${(homingStatus.progress * 100).toFixed(0)}%` }}
+                        />
+                      </div>
+                    </div>
+                  )}
+                </div>
+
+                {/* Frontier Exploration */}
+                <div className="mt-4 bg-slate-900/70 border border-slate-700 rounded-lg p-4 md:p-5 space-y-3 font-mono text-xs">
+                  <div className="flex justify-between text-slate-300">
+                    <span className="text-syntax-orange">explore_budget:</span>
+                    <span className="text-syntax-cyan">{exploreDuration}s</span>
+                  </div>
+                  <input
+                    type="range"
+                    min="30"
+                    max="600"
+                    step="30"
+                    value={exploreDuration}
+                    onChange={(e) => setExploreDuration(parseInt(e.target.value))}
+                    disabled={exploreStatus?.state === "exploring"}
+                    className="glass-slider w-full"
+                  />
+                  {exploreStatus?.state === "exploring" ? (
+                    <button
+                      onClick={() => sendExploreCommand({ command: "stop" })}
+                      disabled={!connection.isConnected}
+                      className="btn-destructive w-full py-2 rounded font-bold flex items-center justify-center gap-1.5 disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+                    >
+                      <Compass className="w-3.5 h-3.5" />
+                      stop_explore()
+                    </button>
+                  ) : (
+                    <button
+                      onClick={() => sendExploreCommand({ command: "start", max_duration_s: exploreDuration })}
+                      disabled={!connection.isConnected || homingStatus?.state === "navigating"}
+                      className="btn-info w-full py-2 rounded font-bold flex items-center justify-center gap-1.5 disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+                    >
+                      <Compass className="w-3.5 h-3.5" />
+                      explore()
+                    </button>
+                  )}
+                  {exploreStatus && exploreStatus.state !== "idle" && (
+                    <div className="flex justify-between text-slate-300">
+                      <span className={exploreStatus.state === "exploring" ? "text-syntax-yellow" : "text-syntax-green"}>
+                        explore: {exploreStatus.state}
+                      </span>
+                      <span className="text-syntax-cyan">
+                        {exploreStatus.explored_area_m2.toFixed(1)} <span className="text-slate-500">m² · {exploreStatus.frontiers_remaining} frontiers</span>
+                      </span>
+                    </div>
+                  )}
+                </div>
+
+                {/* Docking */}
+                <div className="mt-4 bg-slate-900/70 border border-slate-700 rounded-lg p-4 md:p-5 space-y-3 font-mono text-xs">
+                  {dockingStatus && !["idle", "docked", "failed"].includes(dockingStatus.state) ? (
+                    <button
+                      onClick={() => sendDockingCommand({ command: "cancel" })}
+                      disabled={!connection.isConnected}
+                      className="btn-destructive w-full py-2 rounded font-bold flex items-center justify-center gap-1.5 disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+                    >
+                      <PlugZap className="w-3.5 h-3.5" />
+                      cancel_dock()
+                    </button>
+                  ) : dockingStatus?.state === "docked" ? (
+                    <button
+                      onClick={() => sendDockingCommand({ command: "undock" })}
+                      disabled={!connection.isConnected}
+                      className="btn-secondary w-full py-2 rounded font-bold flex items-center justify-center gap-1.5 disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+                    >
+                      <PlugZap className="w-3.5 h-3.5" />
+                      undock()
+                    </button>
+                  ) : (
+                    <button
+                      onClick={() => sendDockingCommand({ command: "dock" })}
+                      disabled={!connection.isConnected || homingStatus?.state === "navigating"}
+                      className="btn-success w-full py-2 rounded font-bold flex items-center justify-center gap-1.5 disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+                    >
+                      <PlugZap className="w-3.5 h-3.5" />
+                      dock()
+                    </button>
+                  )}
+                  {dockingStatus && dockingStatus.state !== "idle" && (
+                    <div className="space-y-1">
+                      <div className="flex justify-between text-slate-300">
+                        <span
+                          className={
+                            dockingStatus.state === "failed"
+                              ? "text-syntax-red"
+                              : dockingStatus.state === "docked"
+                                ? "text-syntax-green"
+                                : "text-syntax-orange"
+                          }
+                        >
+                          docking: {dockingStatus.state}
+                          {dockingStatus.auto_triggered && <span className="text-slate-500"> (auto)</span>}
+                        </span>
+                        {dockingStatus.distance_m !== undefined && (
+                          <span className="text-syntax-cyan">
+                            {dockingStatus.distance_m.toFixed(2)} <span className="text-slate-500">m</span>
+                          </span>
+                        )}
+                      </div>
+                      {dockingStatus.lateral_error_m !== undefined && dockingStatus.heading_error_rad !== undefined && (
+                        <div className="flex justify-between text-slate-500">
+                          <span>lat: {(dockingStatus.lateral_error_m * 100).toFixed(1)}cm</span>
+                          <span>hdg: {((dockingStatus.heading_error_rad * 180) / Math.PI).toFixed(1)}°</span>
+                        </div>
+                      )}
+                    </div>
+                  )}
+                </div>
+
+                {/* Obstacle Guard */}
+                {obstacleGuard && (
+                  <div className="mt-4 bg-slate-900/70 border border-slate-700 rounded-lg p-4 md:p-5 space-y-3 font-mono text-xs">
+                    <div className="flex items-center justify-between">
+                      <span className="text-syntax-orange">obstacle_stop:</span>
+                      <button
+                        onClick={() => updateObstacleGuard({ enabled: !obstacleGuard.enabled })}
+                        disabled={!connection.isConnected}
+                        className={`px-2 py-1 rounded font-bold cursor-pointer disabled:opacity-50 disabled:cursor-not-allowed ${
+                          obstacleGuard.enabled ? "btn-success" : "btn-destructive"
+                        }`}
+                      >
+                        {obstacleGuard.enabled ? "true" : "false"}
+                      </button>
+                    </div>
+                    <div className="flex justify-between text-slate-300">
+                      <span className="text-syntax-orange">nearest:</span>
+                      <span className={obstacleGuard.intervening ? "text-syntax-red font-bold" : "text-syntax-cyan"}>
+                        {obstacleGuard.nearest_m !== null ? `${obstacleGuard.nearest_m.toFixed(2)} m` : "clear"}
+                      </span>
+                    </div>
+                    {([
+                      ["stop_distance_m", "stop_distance", 0.05, 1.0],
+                      ["slow_distance_m", "slow_distance", 0.1, 2.0],
+                    ] as const).map(([key, label, min, max]) => (
+                      <div key={key} className="space-y-1">
+                        <div className="flex justify-between text-slate-300">
+                          <span className="text-syntax-orange">{label}:</span>
+                          <span className="text-syntax-cyan">
+                            {(guardDraft ?? obstacleGuard)[key].toFixed(2)} <span className="text-slate-500">m</span>
+                          </span>
+                        </div>
+                        <input
+                          type="range"
+                          min={min}
+                          max={max}
+                          step="0.05"
+                          value={(guardDraft ?? obstacleGuard)[key]}
+                          onChange={(e) => {
+                            const value = parseFloat(e.target.value);
+                            setGuardDraft((prev) => ({
+                              stop_distance_m: prev?.stop_distance_m ?? obstacleGuard.stop_distance_m,
+                              slow_distance_m: prev?.slow_distance_m ?? obstacleGuard.slow_distance_m,
+                              [key]: value,
+                            }));
+                          }}
+                          onMouseUp={commitGuardDraft}
+                          onTouchEnd={commitGuardDraft}
+                          onKeyUp={commitGuardDraft}
+                          disabled={!connection.isConnected || !obstacleGuard.enabled}
+                          className="glass-slider w-full"
+                        />
+                      </div>
+                    ))}
+                  </div>
+                )}
               </div>
             </div>
 
@@ -857,6 +1898,52 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
                   onJointChange={updateJoint}
                   disabled={!connection.isConnected}
                 />
+                <div className="mt-4 bg-slate-900/70 border border-slate-700 rounded-lg p-4 space-y-3 font-mono text-xs">
+                  <div className="flex justify-between text-slate-300">
+                    <span className="text-syntax-orange">gripper.width:</span>
+                    <span className="text-syntax-cyan">
+                      {(gripperTarget.width * 1000).toFixed(0)} <span className="text-slate-500">mm</span>
+                    </span>
+                  </div>
+                  <input
+                    type="range"
+                    min="0"
+                    max="0.08"
+                    step="0.002"
+                    value={gripperTarget.width}
+                    onChange={(e) =>
+                      setGripperTarget((prev) => ({ ...prev, width: parseFloat(e.target.value) }))
+                    }
+                    disabled={!connection.isConnected}
+                    className="glass-slider w-full"
+                  />
+                  <div className="flex justify-between text-slate-300">
+                    <span className="text-syntax-orange">gripper.max_force:</span>
+                    <span className="text-syntax-cyan">
+                      {gripperTarget.max_force.toFixed(0)} <span className="text-slate-500">N</span>
+                    </span>
+                  </div>
+                  <input
+                    type="range"
+                    min="1"
+                    max="30"
+                    step="1"
+                    value={gripperTarget.max_force}
+                    onChange={(e) =>
+                      setGripperTarget((prev) => ({ ...prev, max_force: parseFloat(e.target.value) }))
+                    }
+                    disabled={!connection.isConnected}
+                    className="glass-slider w-full"
+                  />
+                  <button
+                    onClick={sendGripper}
+                    disabled={!connection.isConnected}
+                    className="w-full py-2 btn-info rounded font-bold text-sm flex items-center justify-center gap-2 disabled:opacity-50 disabled:cursor-not-allowed cursor-pointer"
+                  >
+                    <Grab className="w-4 h-4" />
+                    <span>{">"} GRIP()</span>
+                  </button>
+                </div>
                 <button
                   onClick={sendHome}
                   disabled={!connection.isConnected}
@@ -866,9 +1953,322 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
                   <span>{">"} HOME_POSITION()</span>
                 </button>
               </CollapsibleSection>
+
+              <CollapsibleSection
+                title={`MOTION_MACROS${macroStatus?.state === "recording" ? " [REC]" : ""}`}
+                isExpanded={expandedSections.macros}
+                onToggle={() => {
+                  if (!expandedSections.macros && connection.isConnected) {
+                    sendMacroCommand({ command: "list" });
+                  }
+                  setExpandedSections((prev) => ({
+                    ...prev,
+                    macros: !prev.macros,
+                  }));
+                }}
+                headerRight={
+                  <IconBadge icon={ListVideo} color="text-syntax-green" size="md" />
+                }
+              >
+                <MacroPanel
+                  status={macroStatus}
+                  onCommand={sendMacroCommand}
+                  disabled={!connection.isConnected}
+                />
+              </CollapsibleSection>
+
+              <CollapsibleSection
+                title="CARTESIAN_MOVE"
+                isExpanded={expandedSections.cartesian}
+                onToggle={() =>
+                  setExpandedSections((prev) => ({
+                    ...prev,
+                    cartesian: !prev.cartesian,
+                  }))
+                }
+                headerRight={
+                  <IconBadge icon={Move3d} color="text-syntax-cyan" size="md" />
+                }
+              >
+                <div className="grid grid-cols-3 gap-2 font-mono text-xs">
+                  {(Object.keys(cartesianDraft) as (keyof CartesianPose)[]).map((axis) => (
+                    <label key={axis} className="space-y-1">
+                      <span className="text-syntax-orange">
+                        {axis}
+                        <span className="text-slate-600">
+                          {" "}({axis === "x" || axis === "y" || axis === "z" ? "m" : "rad"})
+                        </span>
+                      </span>
+                      <input
+                        type="number"
+                        step={axis === "x" || axis === "y" || axis === "z" ? 0.01 : 0.05}
+                        value={cartesianDraft[axis]}
+                        onChange={(e) =>
+                          setCartesianDraft((prev) => ({
+                            ...prev,
+                            [axis]: e.target.value,
+                          }))
+                        }
+                        onKeyDown={(e) => { if (e.key === "Enter") sendCartesianMove(); }}
+                        disabled={!connection.isConnected}
+                        className="glass-input w-full px-2 py-1.5 rounded text-sm font-mono focus:outline-none focus:ring-2 focus:ring-cyan-400/50"
+                      />
+                    </label>
+                  ))}
+                </div>
+                <button
+                  onClick={sendCartesianMove}
+                  disabled={!connection.isConnected}
+                  className="w-full py-3 btn-info rounded-lg font-mono font-bold text-sm flex items-center justify-center gap-2 disabled:opacity-50 disabled:cursor-not-allowed mt-4 cursor-pointer"
+                >
+                  <Move3d className="w-4 h-4" />
+                  <span>{">"} MOVE_TO_POSE()</span>
+                </button>
+              </CollapsibleSection>
             </div>
           </div>
 
+          {/* Visual Servo Tuning */}
+          <CollapsibleSection
+            title="SERVO_TUNING"
+            isExpanded={expandedSections.servoTuning}
+            onToggle={() =>
+              setExpandedSections((prev) => ({
+                ...prev,
+                servoTuning: !prev.servoTuning,
+              }))
+            }
+            headerRight={
+              <IconBadge icon={SlidersHorizontal} color="text-syntax-yellow" size="md" />
+            }
+          >
+            <ServoTuningPanel
+              config={servoTelemetry?.config ?? null}
+              onApply={sendServoConfig}
+              disabled={!connection.isConnected}
+            />
+          </CollapsibleSection>
+
+          {/* Runtime Parameters */}
+          <CollapsibleSection
+            title={`PARAMETERS${params.length > 0 ? ` [${params.length}]` : ""}`}
+            isExpanded={expandedSections.params}
+            onToggle={() => {
+              if (!expandedSections.params && connection.isConnected) {
+                requestParams();
+              }
+              setExpandedSections((prev) => ({
+                ...prev,
+                params: !prev.params,
+              }));
+            }}
+            headerRight={
+              <IconBadge icon={Wrench} color="text-syntax-blue" size="md" />
+            }
+          >
+            <ParameterPanel
+              params={params}
+              onSet={setParam}
+              onRefresh={requestParams}
+              disabled={!connection.isConnected}
+            />
+          </CollapsibleSection>
+
+          {/* Behavior Tree */}
+          <CollapsibleSection
+            title={`BEHAVIOR_TREE${behaviorTree ? ` [${behaviorTree.root.status.toUpperCase()}]` : ""}`}
+            isExpanded={expandedSections.behaviorTree}
+            onToggle={() =>
+              setExpandedSections((prev) => ({
+                ...prev,
+                behaviorTree: !prev.behaviorTree,
+              }))
+            }
+            headerRight={
+              <IconBadge icon={GitBranch} color="text-syntax-purple" size="md" />
+            }
+            contentClassName="bg-slate-950 border-2 border-slate-800 rounded-lg p-3 md:p-4 max-h-64 overflow-y-auto font-mono text-xs"
+          >
+            <BehaviorTreeView status={behaviorTree} />
+          </CollapsibleSection>
+
+          {/* Pipeline Node Health */}
+          <CollapsibleSection
+            title={`NODE_HEALTH${nodeHealth ? ` [${nodeHealth.nodes.length - unhealthyNodes}/${nodeHealth.nodes.length}]` : ""}`}
+            isExpanded={expandedSections.nodeHealth}
+            onToggle={() =>
+              setExpandedSections((prev) => ({
+                ...prev,
+                nodeHealth: !prev.nodeHealth,
+              }))
+            }
+            headerRight={
+              <IconBadge icon={HeartPulse} color={unhealthyNodes > 0 ? "text-syntax-red" : "text-syntax-green"} size="md" />
+            }
+            contentClassName="bg-slate-950 border-2 border-slate-800 rounded-lg p-3 md:p-4 max-h-64 overflow-y-auto font-mono text-xs space-y-1"
+          >
+            {!nodeHealth || nodeHealth.nodes.length === 0 ? (
+              <div className="text-slate-600 text-center py-8">
+                // no node heartbeats yet
+              </div>
+            ) : (
+              nodeHealth.nodes.map((node) => {
+                const maxQueue = Math.max(0, ...Object.values(node.queue_depths));
+                return (
+                  <div key={`${node.entity_id ?? "orchestra"}/${node.node}`} className="grid grid-cols-4 gap-2 text-slate-400">
+                    <span className="text-syntax-cyan truncate col-span-2">
+                      {node.entity_id ? `${node.entity_id}/` : ""}{node.node}
+                    </span>
+                    <span
+                      className={
+                        node.state === "alive"
+                          ? "text-syntax-green"
+                          : node.state === "stalled"
+                            ? "text-syntax-yellow"
+                            : "text-syntax-red"
+                      }
+                    >
+                      {node.state}
+                    </span>
+                    <span title={Object.entries(node.queue_depths).map(([id, depth]) => `${id}: ${depth}`).join("\n")}>
+                      {node.last_input_age_ms < 1000
+                        ? `${node.last_input_age_ms.toFixed(0)}ms`
+                        : `${(node.last_input_age_ms / 1000).toFixed(1)}s`}
+                      {maxQueue > 0 && <span className="text-syntax-orange"> q{maxQueue}</span>}
+                    </span>
+                  </div>
+                );
+              })
+            )}
+          </CollapsibleSection>
+
+          {/* Pipeline Node Errors */}
+          <CollapsibleSection
+            title={`NODE_ERRORS${nodeErrors.size > 0 ? ` [${nodeErrors.size}]` : ""}`}
+            isExpanded={expandedSections.nodeErrors}
+            onToggle={() =>
+              setExpandedSections((prev) => ({
+                ...prev,
+                nodeErrors: !prev.nodeErrors,
+              }))
+            }
+            headerRight={
+              <IconBadge icon={Bug} color={nodeErrors.size > 0 ? "text-syntax-red" : "text-slate-500"} size="md" />
+            }
+            contentClassName="bg-slate-950 border-2 border-slate-800 rounded-lg p-3 md:p-4 max-h-64 overflow-y-auto font-mono text-xs space-y-2"
+          >
+            {nodeErrors.size === 0 ? (
+              <div className="text-slate-600 text-center py-8">
+                // no node errors reported
+              </div>
+            ) : (
+              <>
+                {Array.from(nodeErrors.entries())
+                  .sort(([, a], [, b]) => b.timestamp - a.timestamp)
+                  .map(([key, err]) => (
+                    <div key={key} className="space-y-0.5">
+                      <div className="flex justify-between gap-2">
+                        <span className="text-syntax-cyan truncate">{key}</span>
+                        <span className="text-slate-500 flex-shrink-0">
+                          {err.kind} · x{err.count} · {new Date(err.timestamp).toLocaleTimeString()}
+                        </span>
+                      </div>
+                      <div className="text-syntax-red pl-3 break-all">{err.message}</div>
+                    </div>
+                  ))}
+                <button
+                  onClick={() => setNodeErrors(new Map())}
+                  className="text-slate-500 hover:text-syntax-cyan cursor-pointer"
+                >
+                  clear()
+                </button>
+              </>
+            )}
+          </CollapsibleSection>
+
+          {/* Connected Operators */}
+          <CollapsibleSection
+            title={`OPERATORS${presence ? ` [${presence.operators.length}]` : ""}`}
+            isExpanded={expandedSections.operators}
+            onToggle={() =>
+              setExpandedSections((prev) => ({
+                ...prev,
+                operators: !prev.operators,
+              }))
+            }
+            headerRight={
+              <IconBadge icon={Users} color="text-syntax-blue" size="md" />
+            }
+            contentClassName="bg-slate-950 border-2 border-slate-800 rounded-lg p-3 md:p-4 max-h-64 overflow-y-auto font-mono text-xs space-y-1"
+          >
+            {!presence || presence.operators.length === 0 ? (
+              <div className="text-slate-600 text-center py-8">
+                // no operators connected
+              </div>
+            ) : (
+              presence.operators.map((op) => (
+                <div key={op.client_id} className="grid grid-cols-4 gap-2 text-slate-400">
+                  <span className="text-syntax-cyan truncate">
+                    {op.username}
+                    {op.client_id === socketRef.current?.id && <span className="text-slate-500"> (you)</span>}
+                  </span>
+                  <span className={op.role === "viewer" ? "text-slate-500" : "text-syntax-purple"}>{op.role}</span>
+                  <span className="truncate">
+                    {op.controlled_rovers.length > 0 ? (
+                      <span className="text-syntax-green">ctl: {op.controlled_rovers.join(", ")}</span>
+                    ) : (
+                      op.selected_rover ?? "--"
+                    )}
+                  </span>
+                  <span className={op.idle_s > 300 ? "text-syntax-yellow" : ""}>
+                    idle {op.idle_s < 60 ? `${op.idle_s.toFixed(0)}s` : `${(op.idle_s / 60).toFixed(0)}m`}
+                  </span>
+                </div>
+              ))
+            )}
+          </CollapsibleSection>
+
+          {/* Software Inventory */}
+          <CollapsibleSection
+            title={`FLEET_INVENTORY${inventory?.mixed_versions ? " [MIXED]" : ""}`}
+            isExpanded={expandedSections.inventory}
+            onToggle={() => {
+              if (!expandedSections.inventory) {
+                socketRef.current?.emit("fleet_inventory_request");
+              }
+              setExpandedSections((prev) => ({
+                ...prev,
+                inventory: !prev.inventory,
+              }));
+            }}
+            headerRight={
+              <IconBadge icon={Package} color="text-syntax-orange" size="md" />
+            }
+            contentClassName="bg-slate-950 border-2 border-slate-800 rounded-lg p-3 md:p-4 max-h-64 overflow-y-auto font-mono text-xs space-y-3"
+          >
+            {!inventory ? (
+              <div className="text-slate-600 text-center py-8">
+                // no inventory reported yet
+              </div>
+            ) : (
+              [{ entity_id: "orchestra", nodes: inventory.orchestra }, ...inventory.rovers].map((group) => (
+                <div key={group.entity_id} className="space-y-1">
+                  <div className="text-syntax-purple">{group.entity_id}</div>
+                  {group.nodes.map((node) => (
+                    <div key={node.node_id} className="grid grid-cols-4 gap-2 pl-3 text-slate-400">
+                      <span className="text-syntax-cyan truncate">{node.node_id}</span>
+                      <span>{node.crate_version}</span>
+                      <span className="text-syntax-yellow">{node.git_hash.slice(0, 8)}</span>
+                      <span className={node.build_profile === "release" ? "text-syntax-green" : "text-syntax-orange"}>
+                        {node.build_profile}
+                      </span>
+                    </div>
+                  ))}
+                </div>
+              ))
+            )}
+          </CollapsibleSection>
+
           {/* Activity Logs - Terminal style */}
           <CollapsibleSection
             title={`SYSTEM_LOG [${logs.length}]`}
@@ -930,6 +2330,16 @@ export const RoboRoverControl: React.FC<RoboRoverControlProps> = ({
                 <span className="text-slate-500">ms</span>
               </div>
               <div className="hidden md:block w-px h-6 bg-slate-700"></div>
+              <div>
+                <span className="text-syntax-orange">heartbeat</span>
+                <span className="text-slate-600">:</span>{" "}
+                <span className="text-syntax-yellow">{HEARTBEAT_INTERVAL}</span>
+                <span className="text-slate-500">ms</span>
+                <span className="text-slate-600">/</span>
+                <span className="text-syntax-yellow">{DEADMAN_TIMEOUT}</span>
+                <span className="text-slate-500">ms</span>
+              </div>
+              <div className="hidden md:block w-px h-6 bg-slate-700"></div>
               <div className="flex items-center gap-2">
                 <span className="text-syntax-purple">map_visible</span>
                 <span className="text-slate-600">:</span>{" "}
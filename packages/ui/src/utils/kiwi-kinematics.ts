@@ -0,0 +1,58 @@
+/**
+ * Kiwi-drive (3-wheel omni, 120° spacing) kinematics.
+ * Mirrors the kinematics module in robo_rover_lib so the UI's wheel
+ * visualization matches what rover-controller actually commands.
+ */
+
+export interface KiwiGeometry {
+  /** Wheel radius in meters */
+  wheelRadius: number;
+  /** Distance from chassis center to each wheel contact in meters */
+  robotRadius: number;
+}
+
+export interface BodyVelocity {
+  v_x: number; // m/s, forward
+  v_y: number; // m/s, left
+  omega_z: number; // rad/s, counter-clockwise
+}
+
+export type WheelVelocities = [number, number, number]; // rad/s
+
+export const LEKIWI_GEOMETRY: KiwiGeometry = {
+  wheelRadius: 0.05,
+  robotRadius: 0.15,
+};
+
+const SIN_60 = Math.sqrt(3) / 2;
+
+/**
+ * Converts a body-frame velocity into the three wheel angular velocities.
+ */
+export function bodyToWheelVelocities(
+  { v_x, v_y, omega_z }: BodyVelocity,
+  { wheelRadius, robotRadius }: KiwiGeometry = LEKIWI_GEOMETRY,
+): WheelVelocities {
+  const spin = omega_z * robotRadius;
+  return [
+    (v_y + spin) / wheelRadius,
+    (-0.5 * v_y + SIN_60 * v_x + spin) / wheelRadius,
+    (-0.5 * v_y - SIN_60 * v_x + spin) / wheelRadius,
+  ];
+}
+
+/**
+ * Inverse of bodyToWheelVelocities — recovers the body-frame velocity
+ * from measured wheel angular velocities (odometry).
+ */
+export function wheelToBodyVelocity(
+  [w1, w2, w3]: WheelVelocities,
+  { wheelRadius, robotRadius }: KiwiGeometry = LEKIWI_GEOMETRY,
+): BodyVelocity {
+  const [s1, s2, s3] = [w1 * wheelRadius, w2 * wheelRadius, w3 * wheelRadius];
+  return {
+    v_x: (s2 - s3) / (2 * SIN_60),
+    v_y: (2 * s1 - s2 - s3) / 3,
+    omega_z: (s1 + s2 + s3) / (3 * robotRadius),
+  };
+}